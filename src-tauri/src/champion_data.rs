@@ -0,0 +1,182 @@
+//! File: champion_data.rs
+//! Author: Wildflover
+//! Description: Champion data (id -> key/name) cached from Data Dragon
+//!              - Local cache in champion_data.json with its Data Dragon version
+//!              - Lookup helpers for other backend modules
+//!              - Refresh from the Data Dragon version matching the game patch
+//! Language: Rust
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// [STATE] Set while a refresh downloads - concurrent refreshes would race on champion_data.json
+static REFRESH_IN_FLIGHT: AtomicBool = AtomicBool::new(false);
+
+// [STRUCT] Clears REFRESH_IN_FLIGHT when the refresh ends, including early returns
+struct RefreshGuard;
+
+impl Drop for RefreshGuard {
+    fn drop(&mut self) {
+        REFRESH_IN_FLIGHT.store(false, Ordering::SeqCst);
+    }
+}
+
+// [STRUCT] Single champion entry
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ChampionInfo {
+    pub id: i32,
+    pub key: String,
+    pub name: String,
+}
+
+// [STRUCT] Cached champion data with its Data Dragon version
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct ChampionData {
+    pub version: String,
+    pub champions: HashMap<i32, ChampionInfo>,
+    // Game patch the refresh was made for - differs from version when Data Dragon lagged behind
+    #[serde(default)]
+    pub requested_patch: Option<String>,
+}
+
+// [CONST] Data Dragon endpoints
+const DDRAGON_VERSIONS_URL: &str = "https://ddragon.leagueoflegends.com/api/versions.json";
+const DDRAGON_CDN_URL: &str = "https://ddragon.leagueoflegends.com/cdn";
+
+// [STRUCT] Data Dragon champion.json - only the fields we keep
+#[derive(Deserialize)]
+struct DdragonChampionFile {
+    data: HashMap<String, DdragonChampion>,
+}
+
+#[derive(Deserialize)]
+struct DdragonChampion {
+    id: String,
+    key: String,
+    name: String,
+}
+
+// [FUNC] Get champion data cache file location
+fn get_champion_data_file() -> PathBuf {
+    let app_data = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
+    app_data.join("Wildflover").join("champion_data.json")
+}
+
+// [FUNC] Load cached champion data - None if never fetched
+pub fn load_champion_data() -> Option<ChampionData> {
+    let content = std::fs::read_to_string(get_champion_data_file()).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+// [FUNC] Champion id from a WAD/alias key (e.g. "MonkeyKing") - case-insensitive, falls back to display name
+pub fn champion_id_by_key(data: &ChampionData, key: &str) -> Option<i32> {
+    let normalize = |s: &str| s.chars().filter(|c| c.is_alphanumeric()).collect::<String>().to_lowercase();
+    let wanted = normalize(key);
+
+    data.champions
+        .values()
+        .find(|c| normalize(&c.key) == wanted)
+        .or_else(|| data.champions.values().find(|c| normalize(&c.name) == wanted))
+        .map(|c| c.id)
+}
+
+// [FUNC] Data Dragon version of the cached champion data
+pub fn champion_data_version() -> Option<String> {
+    load_champion_data()
+        .map(|data| data.version)
+        .filter(|v| !v.is_empty())
+}
+
+// [FUNC] Pick the Data Dragon version for a game patch (e.g. "14.20" -> "14.20.1")
+// Falls back to the newest version when the patch is unknown or not yet published
+async fn resolve_ddragon_version(client: &reqwest::Client, patch: Option<&str>) -> Result<String, String> {
+    let versions: Vec<String> = client
+        .get(DDRAGON_VERSIONS_URL)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch Data Dragon versions: {}", e))?
+        .json()
+        .await
+        .map_err(|e| format!("Invalid Data Dragon versions: {}", e))?;
+
+    let matching = patch.and_then(|p| versions.iter().find(|v| crate::game_version::patch_of(v) == p));
+    matching
+        .or_else(|| versions.first())
+        .cloned()
+        .ok_or_else(|| "Data Dragon returned no versions".to_string())
+}
+
+// [FUNC] Download champion.json for a game patch and replace the local cache
+pub async fn refresh_champion_data_for_patch(patch: Option<&str>) -> Result<String, String> {
+    if REFRESH_IN_FLIGHT.swap(true, Ordering::SeqCst) {
+        return Err("Champion data refresh already running".to_string());
+    }
+    let _guard = RefreshGuard;
+
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(30))
+        .build()
+        .unwrap_or_else(|_| reqwest::Client::new());
+
+    let version = resolve_ddragon_version(&client, patch).await?;
+    let url = format!("{}/{}/data/en_US/champion.json", DDRAGON_CDN_URL, version);
+
+    let file: DdragonChampionFile = client
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch champion data: {}", e))?
+        .json()
+        .await
+        .map_err(|e| format!("Invalid champion data: {}", e))?;
+
+    // Data Dragon "key" is the numeric id, "id" is the alias used in WAD names
+    let champions: HashMap<i32, ChampionInfo> = file
+        .data
+        .into_values()
+        .filter_map(|c| {
+            let id = c.key.parse::<i32>().ok()?;
+            Some((id, ChampionInfo { id, key: c.id, name: c.name }))
+        })
+        .collect();
+
+    let data = ChampionData {
+        version: version.clone(),
+        champions,
+        requested_patch: patch.map(String::from),
+    };
+    let cache_file = get_champion_data_file();
+    if let Some(parent) = cache_file.parent() {
+        std::fs::create_dir_all(parent).ok();
+    }
+    let json = serde_json::to_string(&data).map_err(|e| format!("Failed to serialize champion data: {}", e))?;
+    std::fs::write(&cache_file, json).map_err(|e| format!("Failed to save champion data: {}", e))?;
+
+    println!("[CHAMPION-DATA] Refreshed {} champions from Data Dragon {}", data.champions.len(), version);
+    Ok(version)
+}
+
+// [FUNC] Whether the cached data already matches a game patch - or was already refreshed for it
+// A refresh that fell back to an older Data Dragon version still counts, so it isn't retried on every check
+pub fn champion_data_matches_patch(patch: &str) -> bool {
+    load_champion_data()
+        .map(|data| {
+            crate::game_version::patch_of(&data.version) == patch
+                || data.requested_patch.as_deref() == Some(patch)
+        })
+        .unwrap_or(false)
+}
+
+// [COMMAND] Re-download champion data - patch selects the matching Data Dragon version, None uses the latest
+#[tauri::command]
+pub async fn refresh_champion_data(patch: Option<String>) -> Result<String, String> {
+    refresh_champion_data_for_patch(patch.as_deref()).await
+}
+
+// [COMMAND] Data Dragon version of the cached champion names - None if never fetched
+#[tauri::command]
+pub async fn get_champion_data_version() -> Option<String> {
+    champion_data_version()
+}
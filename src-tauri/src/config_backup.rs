@@ -0,0 +1,131 @@
+//! File: config_backup.rs
+//! Author: Wildflover
+//! Description: Portable backup of the backend configuration
+//!              - Versioned JSON containing settings.json and the saved game path
+//!              - Secrets (tokens, sessions) are never exported
+//!              - Import validates and clamps values before applying them
+//! Language: Rust
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use crate::settings::{
+    update_settings, load_settings, AppSettings,
+    MIN_IMPORT_CONCURRENCY, MAX_IMPORT_CONCURRENCY,
+    MIN_CONCURRENT_DOWNLOADS, MAX_CONCURRENT_DOWNLOADS,
+    MIN_OVERLAY_VERIFY_DELAY_MS, MAX_OVERLAY_VERIFY_DELAY_MS,
+};
+
+// [CONST] Config backup identity - bump version on breaking format changes
+const CONFIG_FORMAT: &str = "wildflover-config";
+const CONFIG_VERSION: u32 = 1;
+
+// [CONST] Data deliberately left out of backups - tied to this machine or account
+const NOT_PORTABLE: [&str; 3] = [
+    "GitHub token",
+    "Discord login session",
+    "Discord webhook URL",
+];
+
+// [STRUCT] Config backup contents
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigBackup {
+    pub format: String,
+    pub version: u32,
+    #[serde(default)]
+    pub exported_at: Option<String>,
+    pub settings: AppSettings,
+    #[serde(default)]
+    pub game_path: Option<String>,
+    #[serde(default)]
+    pub not_included: Vec<String>,
+}
+
+// [STRUCT] Config import summary
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigImportResult {
+    pub settings_applied: bool,
+    pub game_path_applied: bool,
+    pub warnings: Vec<String>,
+}
+
+// [COMMAND] Serialize all persisted backend settings to a portable JSON string
+#[tauri::command]
+pub async fn export_config() -> Result<String, String> {
+    let backup = ConfigBackup {
+        format: CONFIG_FORMAT.to_string(),
+        version: CONFIG_VERSION,
+        exported_at: Some(chrono::Utc::now().to_rfc3339()),
+        settings: load_settings(),
+        game_path: crate::mod_manager::read_game_path_config(),
+        not_included: NOT_PORTABLE.iter().map(|s| s.to_string()).collect(),
+    };
+
+    println!("[CONFIG-BACKUP] Exported config v{}", CONFIG_VERSION);
+    serde_json::to_string_pretty(&backup).map_err(|e| format!("Failed to serialize config: {}", e))
+}
+
+// [COMMAND] Validate and apply a config backup produced by export_config
+#[tauri::command]
+pub async fn import_config(data: String) -> Result<ConfigImportResult, String> {
+    let backup: ConfigBackup = serde_json::from_str(&data)
+        .map_err(|e| format!("Invalid config file: {}", e))?;
+
+    if backup.format != CONFIG_FORMAT {
+        return Err("Not a Wildflover config file".to_string());
+    }
+    if backup.version > CONFIG_VERSION {
+        return Err(format!("Config version {} is newer than supported ({})", backup.version, CONFIG_VERSION));
+    }
+
+    let mut warnings: Vec<String> = Vec::new();
+    let mut imported = backup.settings;
+
+    imported.import_concurrency = imported.import_concurrency.clamp(MIN_IMPORT_CONCURRENCY, MAX_IMPORT_CONCURRENCY);
+    imported.max_concurrent_downloads = imported.max_concurrent_downloads.clamp(MIN_CONCURRENT_DOWNLOADS, MAX_CONCURRENT_DOWNLOADS);
+    imported.mkoverlay_retries = imported.mkoverlay_retries.clamped();
+    imported.overlay_verify_delay_ms = imported.overlay_verify_delay_ms.clamp(MIN_OVERLAY_VERIFY_DELAY_MS, MAX_OVERLAY_VERIFY_DELAY_MS);
+
+    if imported.skin_repo.owner.trim().is_empty() || imported.skin_repo.repo.trim().is_empty() || imported.skin_repo.branch.trim().is_empty() {
+        warnings.push("Skin repository was incomplete - kept current value".to_string());
+        imported.skin_repo = load_settings().skin_repo;
+    }
+
+    if imported.user_agent.trim().is_empty() {
+        warnings.push("User-Agent was empty - kept current value".to_string());
+        imported.user_agent = load_settings().user_agent;
+    }
+
+    let max_downloads = imported.max_concurrent_downloads;
+    update_settings(|s| *s = imported)?;
+
+    // Resize the live download semaphore as well, not just the stored value
+    crate::download_limiter::set_max_concurrent_downloads(max_downloads).await?;
+
+    // [GAME-PATH] Only applied when the install exists on this machine
+    let mut game_path_applied = false;
+    if let Some(game_path) = backup.game_path {
+        if PathBuf::from(&game_path).join("League of Legends.exe").exists() {
+            let config_path = crate::mod_manager::get_game_path_config();
+            if let Some(parent) = config_path.parent() {
+                std::fs::create_dir_all(parent).ok();
+            }
+            match std::fs::write(&config_path, &game_path) {
+                Ok(()) => game_path_applied = true,
+                Err(e) => warnings.push(format!("Failed to save game path: {}", e)),
+            }
+        } else {
+            warnings.push(format!("Game path not found on this machine: {}", game_path));
+        }
+    }
+
+    println!("[CONFIG-BACKUP] Imported config v{} | game path applied: {} | {} warnings",
+             backup.version, game_path_applied, warnings.len());
+
+    Ok(ConfigImportResult {
+        settings_applied: true,
+        game_path_applied,
+        warnings,
+    })
+}
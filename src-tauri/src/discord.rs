@@ -17,6 +17,22 @@ const DISCORD_CLIENT_SECRET: &str = "YOUR_DISCORD_CLIENT_SECRET";
 const DISCORD_TOKEN_URL: &str = "https://discord.com/api/oauth2/token";
 const DISCORD_REVOKE_URL: &str = "https://discord.com/api/oauth2/token/revoke";
 
+// [FUNC] False while the client id/secret are still the shipped placeholders
+pub fn oauth_configured() -> bool {
+    [DISCORD_CLIENT_ID, DISCORD_CLIENT_SECRET]
+        .iter()
+        .all(|value| !value.trim().is_empty() && !value.starts_with("YOUR_"))
+}
+
+// [CONSTANTS] Redirect URIs the app actually uses - dev server and Tauri production origins
+// Must match the redirects registered in the Discord Developer Portal
+const ALLOWED_REDIRECT_URIS: [&str; 4] = [
+    "http://localhost:1420",
+    "http://tauri.localhost",
+    "https://tauri.localhost",
+    "tauri://localhost",
+];
+
 // [CONSTANTS] Network configuration - Optimized for faster failure detection
 const REQUEST_TIMEOUT_SECS: u64 = 20;
 const CONNECT_TIMEOUT_SECS: u64 = 10;
@@ -41,6 +57,12 @@ pub struct TokenResult {
     pub error: Option<String>,
 }
 
+// [HELPER] Check redirect_uri against the whitelist - trailing slash is ignored
+fn is_allowed_redirect_uri(redirect_uri: &str) -> bool {
+    let normalized = redirect_uri.trim().trim_end_matches('/');
+    ALLOWED_REDIRECT_URIS.contains(&normalized)
+}
+
 // [HELPER] Create HTTP client with proper timeout configuration
 fn create_http_client() -> Result<reqwest::Client, reqwest::Error> {
     reqwest::Client::builder()
@@ -117,6 +139,16 @@ pub async fn discord_exchange_code(code: String, redirect_uri: String) -> TokenR
     println!("[DISCORD-AUTH] Exchanging authorization code for tokens...");
     println!("[DISCORD-AUTH] Using redirect_uri: {}", redirect_uri);
     
+    // [SECURITY] Never forward an unknown redirect to Discord
+    if !is_allowed_redirect_uri(&redirect_uri) {
+        println!("[DISCORD-AUTH] Rejected redirect_uri not on whitelist: {}", redirect_uri);
+        return TokenResult {
+            success: false,
+            data: None,
+            error: Some(format!("Redirect URI not allowed: {}", redirect_uri)),
+        };
+    }
+    
     let client = match create_http_client() {
         Ok(c) => c,
         Err(e) => {
@@ -3,6 +3,8 @@
 //! Description: Discord Rich Presence integration - Optimized async version
 //!              - Non-blocking activity updates
 //!              - Background thread for Discord IPC
+//!              - Enabled state persisted in settings.json and restored on startup
+//!              - User-configurable presence buttons
 //! Language: Rust
 
 use discord_presence::Client;
@@ -14,10 +16,24 @@ use std::thread;
 // [CONSTANTS] Discord Application ID
 const DISCORD_APP_ID: u64 = 1458923588475293872;
 
-// [CONSTANTS] Button configuration
+// [CONSTANTS] Button configuration - default used until set_rpc_buttons provides valid ones
 const BUTTON_LABEL: &str = "Join Discord";
 const BUTTON_URL: &str = "https://discord.gg/nJVc4JSwgW";
 
+// [CONSTANTS] Discord button limits
+const MAX_BUTTONS: usize = 2;
+const MAX_BUTTON_LABEL_LEN: usize = 32;
+const MAX_BUTTON_URL_LEN: usize = 512;
+
+// [CONSTANTS] Uploaded Discord art assets - unknown keys render no image
+const KNOWN_IMAGE_KEYS: &[&str] = &["wildflover_splash_login", "new_icon"];
+const DEFAULT_LARGE_IMAGE: &str = "wildflover_splash_login";
+const DEFAULT_SMALL_IMAGE: &str = "new_icon";
+
+// [CONSTANTS] Discord asset field limits
+const MAX_IMAGE_KEY_LEN: usize = 256;
+const MAX_IMAGE_TEXT_LEN: usize = 128;
+
 // [STATE] Global Discord client
 static DISCORD_CLIENT: Mutex<Option<Client>> = Mutex::new(None);
 
@@ -30,6 +46,9 @@ static START_TIME: Mutex<Option<u64>> = Mutex::new(None);
 // [STATE] Last activity cache to prevent duplicate updates
 static LAST_ACTIVITY: Mutex<Option<String>> = Mutex::new(None);
 
+// [STATE] Custom presence buttons - empty means the default Discord invite button
+static RPC_BUTTONS: Mutex<Vec<RpcButton>> = Mutex::new(Vec::new());
+
 // [STRUCT] RPC result for frontend
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct RpcResult {
@@ -37,6 +56,13 @@ pub struct RpcResult {
     pub message: String,
 }
 
+// [STRUCT] Presence button shown under the activity
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RpcButton {
+    pub label: String,
+    pub url: String,
+}
+
 // [FUNC] Get current unix timestamp
 fn get_unix_timestamp() -> u64 {
     SystemTime::now()
@@ -45,34 +71,102 @@ fn get_unix_timestamp() -> u64 {
         .as_secs()
 }
 
+// [FUNC] Validate an image key - known asset, or external URL served via Discord's media proxy
+// Returns the key to use and a warning when the fallback was applied
+fn validate_image_key(key: &str, fallback: &str) -> (String, Option<String>) {
+    let key = key.trim();
+    
+    if key.is_empty() {
+        return (fallback.to_string(), Some(format!("empty image key, using '{}'", fallback)));
+    }
+    
+    if key.len() > MAX_IMAGE_KEY_LEN {
+        return (fallback.to_string(), Some(format!("image key exceeds {} chars, using '{}'", MAX_IMAGE_KEY_LEN, fallback)));
+    }
+    
+    let is_url = key.starts_with("https://") || key.starts_with("mp:");
+    if is_url || KNOWN_IMAGE_KEYS.contains(&key) {
+        return (key.to_string(), None);
+    }
+    
+    (fallback.to_string(), Some(format!("unknown image key '{}', using '{}'", key, fallback)))
+}
+
+// [FUNC] Clamp hover text to Discord's limit on a char boundary
+fn clamp_image_text(text: &str) -> String {
+    text.chars().take(MAX_IMAGE_TEXT_LEN).collect()
+}
+
+// [FUNC] Buttons applied to the next activity update
+fn active_buttons() -> Vec<RpcButton> {
+    let buttons = RPC_BUTTONS.lock().unwrap().clone();
+    if buttons.is_empty() {
+        vec![RpcButton { label: BUTTON_LABEL.to_string(), url: BUTTON_URL.to_string() }]
+    } else {
+        buttons
+    }
+}
+
+// [COMMAND] Validate an RPC image key without updating the activity
+#[tauri::command]
+pub fn validate_rpc_image_key(key: String) -> RpcResult {
+    match validate_image_key(&key, DEFAULT_LARGE_IMAGE) {
+        (_, None) => RpcResult { success: true, message: "Image key valid".to_string() },
+        (_, Some(warning)) => RpcResult { success: false, message: warning },
+    }
+}
+
+// [FUNC] Connect the Discord client in a background thread - never blocks the caller
+fn spawn_rpc_connect() {
+    thread::spawn(|| {
+        let mut client_guard = DISCORD_CLIENT.lock().unwrap();
+        
+        if client_guard.is_none() {
+            let mut client = Client::new(DISCORD_APP_ID);
+            
+            client.on_ready(|_ctx| {
+                println!("[DISCORD-RPC] Client ready");
+            }).persist();
+            
+            client.start();
+            
+            *client_guard = Some(client);
+            
+            let mut start = START_TIME.lock().unwrap();
+            if start.is_none() {
+                *start = Some(get_unix_timestamp());
+            }
+        }
+        
+        *RPC_ENABLED.lock().unwrap() = true;
+        println!("[DISCORD-RPC] Enabled");
+    });
+}
+
+// [FUNC] Restore the saved RPC state on startup - connection runs in the background
+// Discord not running only means the client keeps retrying, the window is never held up
+pub fn restore_rpc_state() {
+    if crate::settings::load_settings().rpc_enabled {
+        println!("[DISCORD-RPC] Restoring enabled state from settings");
+        spawn_rpc_connect();
+    }
+}
+
+// [FUNC] Remember the user's choice for the next launch
+fn persist_rpc_enabled(enabled: bool) {
+    if let Err(e) = crate::settings::update_settings(|s| s.rpc_enabled = enabled) {
+        println!("[DISCORD-RPC] WARN: Failed to save enabled state: {}", e);
+    }
+}
+
 // [COMMAND] Initialize and enable Discord RPC
 #[tauri::command]
 pub fn set_rpc_enabled(enabled: bool) -> RpcResult {
+    persist_rpc_enabled(enabled);
+    
     if enabled {
         // [ASYNC] Start connection in background thread
-        thread::spawn(|| {
-            let mut client_guard = DISCORD_CLIENT.lock().unwrap();
-            
-            if client_guard.is_none() {
-                let mut client = Client::new(DISCORD_APP_ID);
-                
-                client.on_ready(|_ctx| {
-                    println!("[DISCORD-RPC] Client ready");
-                }).persist();
-                
-                client.start();
-                
-                *client_guard = Some(client);
-                
-                let mut start = START_TIME.lock().unwrap();
-                if start.is_none() {
-                    *start = Some(get_unix_timestamp());
-                }
-            }
-            
-            *RPC_ENABLED.lock().unwrap() = true;
-            println!("[DISCORD-RPC] Enabled");
-        });
+        spawn_rpc_connect();
         
         RpcResult { success: true, message: "RPC enabling".to_string() }
     } else {
@@ -91,6 +185,33 @@ pub fn set_rpc_enabled(enabled: bool) -> RpcResult {
     }
 }
 
+// [COMMAND] Replace the presence buttons - invalid entries are dropped, at most 2 are kept
+// An empty (or fully invalid) list restores the default Discord invite button
+#[tauri::command]
+pub fn set_rpc_buttons(buttons: Vec<RpcButton>) -> RpcResult {
+    let requested = buttons.len();
+    let valid: Vec<RpcButton> = buttons
+        .into_iter()
+        .map(|b| RpcButton { label: b.label.trim().to_string(), url: b.url.trim().to_string() })
+        .filter(|b| {
+            !b.label.is_empty()
+                && b.label.chars().count() <= MAX_BUTTON_LABEL_LEN
+                && b.url.starts_with("https://")
+                && b.url.len() <= MAX_BUTTON_URL_LEN
+        })
+        .take(MAX_BUTTONS)
+        .collect();
+    
+    let count = valid.len();
+    *RPC_BUTTONS.lock().unwrap() = valid;
+    
+    // Buttons are not part of the dedup key - force the next update through
+    *LAST_ACTIVITY.lock().unwrap() = None;
+    
+    println!("[DISCORD-RPC] Buttons set: {} of {} accepted", count, requested);
+    RpcResult { success: true, message: format!("{} buttons set", count) }
+}
+
 // [COMMAND] Check if RPC is enabled
 #[tauri::command]
 pub fn is_rpc_enabled() -> bool {
@@ -113,15 +234,26 @@ pub fn update_activity(
     }
 
     // [CACHE] Create activity hash to prevent duplicates
+    // Only recorded once Discord accepted the activity, so a failed update is retried next time
     let activity_hash = format!("{}|{}", state, details);
-    {
-        let mut last = LAST_ACTIVITY.lock().unwrap();
-        if last.as_ref() == Some(&activity_hash) {
-            return RpcResult { success: true, message: "Activity unchanged".to_string() };
-        }
-        *last = Some(activity_hash);
+    if LAST_ACTIVITY.lock().unwrap().as_ref() == Some(&activity_hash) {
+        return RpcResult { success: true, message: "Activity unchanged".to_string() };
     }
 
+    // [VALIDATE] Fall back to default assets for invalid keys instead of showing no image
+    let (large_image, large_warning) = validate_image_key(&large_image, DEFAULT_LARGE_IMAGE);
+    let small_validated = small_image.as_deref().map(|img| validate_image_key(img, DEFAULT_SMALL_IMAGE));
+    let small_warning = small_validated.as_ref().and_then(|(_, w)| w.clone());
+    let small_image = small_validated.map(|(img, _)| img);
+    let large_text = clamp_image_text(&large_text);
+    let small_text = small_text.map(|t| clamp_image_text(&t));
+    
+    let warnings: Vec<String> = [large_warning, small_warning].into_iter().flatten().collect();
+    for warning in &warnings {
+        println!("[DISCORD-RPC] WARN: {}", warning);
+    }
+    let buttons = active_buttons();
+
     // [ASYNC] Update in background thread
     thread::spawn(move || {
         let mut client_guard = DISCORD_CLIENT.lock().unwrap();
@@ -129,7 +261,7 @@ pub fn update_activity(
         if let Some(ref mut client) = *client_guard {
             let start_time = START_TIME.lock().unwrap().unwrap_or_else(get_unix_timestamp);
             
-            let _ = client.set_activity(|act| {
+            let result = client.set_activity(|act| {
                 let activity = act
                     .state(&state)
                     .details(&details)
@@ -145,21 +277,39 @@ pub fn update_activity(
                         a
                     });
                 
-                activity.append_buttons(|btn| {
-                    btn.label(BUTTON_LABEL).url(BUTTON_URL)
+                buttons.iter().fold(activity, |act, button| {
+                    act.append_buttons(|btn| btn.label(&button.label).url(&button.url))
                 })
             });
             
-            println!("[DISCORD-RPC] Updated: {}", state);
+            match result {
+                Ok(_) => {
+                    *LAST_ACTIVITY.lock().unwrap() = Some(activity_hash);
+                    println!("[DISCORD-RPC] Updated: {}", state);
+                }
+                Err(e) => {
+                    *LAST_ACTIVITY.lock().unwrap() = None;
+                    println!("[DISCORD-RPC] WARN: Activity update failed: {}", e);
+                }
+            }
+        } else {
+            *LAST_ACTIVITY.lock().unwrap() = None;
         }
     });
 
-    RpcResult { success: true, message: "Activity updating".to_string() }
+    if warnings.is_empty() {
+        RpcResult { success: true, message: "Activity updating".to_string() }
+    } else {
+        RpcResult { success: true, message: format!("Activity updating ({})", warnings.join("; ")) }
+    }
 }
 
 // [COMMAND] Clear Discord activity
 #[tauri::command]
 pub fn clear_activity() -> RpcResult {
+    // A cleared presence must not be deduped against the activity shown before it
+    *LAST_ACTIVITY.lock().unwrap() = None;
+    
     thread::spawn(|| {
         let mut client_guard = DISCORD_CLIENT.lock().unwrap();
         if let Some(ref mut client) = *client_guard {
@@ -0,0 +1,51 @@
+//! File: download_limiter.rs
+//! Author: Wildflover
+//! Description: Global throttle for outgoing downloads and GitHub fetches
+//!              - Single semaphore shared by skin, marketplace and preview fetches
+//!              - User-configurable limit persisted in settings
+//! Language: Rust
+
+use std::sync::{Arc, Mutex, OnceLock};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use crate::settings::{load_settings, update_settings, MIN_CONCURRENT_DOWNLOADS, MAX_CONCURRENT_DOWNLOADS};
+
+// [STATE] Current download semaphore - replaced when the limit changes
+static DOWNLOAD_SEMAPHORE: OnceLock<Mutex<Arc<Semaphore>>> = OnceLock::new();
+
+// [FUNC] Get the shared semaphore, sized from settings on first use
+fn current_semaphore() -> Arc<Semaphore> {
+    let holder = DOWNLOAD_SEMAPHORE.get_or_init(|| {
+        let limit = load_settings()
+            .max_concurrent_downloads
+            .clamp(MIN_CONCURRENT_DOWNLOADS, MAX_CONCURRENT_DOWNLOADS);
+        Mutex::new(Arc::new(Semaphore::new(limit as usize)))
+    });
+    holder.lock().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+// [FUNC] Wait for a download slot - hold the permit for the duration of the request
+pub async fn acquire_download_permit() -> Option<OwnedSemaphorePermit> {
+    current_semaphore().acquire_owned().await.ok()
+}
+
+// [COMMAND] Get global concurrent download limit
+#[tauri::command]
+pub async fn get_max_concurrent_downloads() -> u32 {
+    load_settings()
+        .max_concurrent_downloads
+        .clamp(MIN_CONCURRENT_DOWNLOADS, MAX_CONCURRENT_DOWNLOADS)
+}
+
+// [COMMAND] Set global concurrent download limit - applies to new requests immediately
+#[tauri::command]
+pub async fn set_max_concurrent_downloads(n: u32) -> Result<u32, String> {
+    let value = n.clamp(MIN_CONCURRENT_DOWNLOADS, MAX_CONCURRENT_DOWNLOADS);
+    update_settings(|s| s.max_concurrent_downloads = value)?;
+
+    // In-flight requests keep their permits on the old semaphore and drain naturally
+    let holder = DOWNLOAD_SEMAPHORE.get_or_init(|| Mutex::new(Arc::new(Semaphore::new(value as usize))));
+    *holder.lock().unwrap_or_else(|e| e.into_inner()) = Arc::new(Semaphore::new(value as usize));
+
+    println!("[DOWNLOAD-LIMIT] Max concurrent downloads set to {}", value);
+    Ok(value)
+}
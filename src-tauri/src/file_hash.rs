@@ -0,0 +1,81 @@
+//! File: file_hash.rs
+//! Author: Wildflover
+//! Description: Streaming SHA-256 file hashing
+//!              - Chunked reads so large WADs never load fully into memory
+//!              - Shared by cache dedup, upload integrity and version info
+//!              - In-memory digest for freshly downloaded archives
+//! Language: Rust
+
+use sha2::{Digest, Sha256};
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+// [CONST] Read buffer size for streaming file contents
+const HASH_CHUNK_SIZE: usize = 64 * 1024;
+
+// [FUNC] Stream a file through SHA-256 - returns lowercase hex digest
+pub fn hash_file(path: &Path) -> Result<String, std::io::Error> {
+    let mut file = File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0u8; HASH_CHUNK_SIZE];
+
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+
+    Ok(to_hex(&hasher.finalize()))
+}
+
+// [FUNC] SHA-256 of an in-memory buffer - lowercase hex digest
+pub fn hash_bytes(bytes: &[u8]) -> String {
+    to_hex(&Sha256::digest(bytes))
+}
+
+// [FUNC] Lowercase hex encoding of a digest
+fn to_hex(digest: &[u8]) -> String {
+    digest.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+// [COMMAND] Get SHA-256 of a file for dedup and integrity checks
+#[tauri::command]
+pub async fn get_file_hash(path: String) -> Result<String, String> {
+    tokio::task::spawn_blocking(move || hash_file(Path::new(&path)).map_err(|e| format!("Failed to hash {}: {}", path, e)))
+        .await
+        .map_err(|e| format!("Hash task failed: {}", e))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EMPTY_SHA256: &str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+    const ABC_SHA256: &str = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+
+    // [FUNC] Write contents to a unique temp file and hash it
+    fn hash_temp_file(name: &str, contents: &[u8]) -> String {
+        let path = std::env::temp_dir().join(format!("wildflover_hash_{}_{}", std::process::id(), name));
+        std::fs::write(&path, contents).unwrap();
+        let digest = hash_file(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        digest
+    }
+
+    #[test]
+    fn hashes_known_digests() {
+        assert_eq!(hash_temp_file("empty", b""), EMPTY_SHA256);
+        assert_eq!(hash_temp_file("abc", b"abc"), ABC_SHA256);
+        assert_eq!(hash_bytes(b""), EMPTY_SHA256);
+        assert_eq!(hash_bytes(b"abc"), ABC_SHA256);
+    }
+
+    #[test]
+    fn file_and_bytes_agree_across_chunks() {
+        let contents: Vec<u8> = (0..HASH_CHUNK_SIZE * 2 + 17).map(|i| (i % 251) as u8).collect();
+        assert_eq!(hash_temp_file("chunks", &contents), hash_bytes(&contents));
+    }
+}
@@ -0,0 +1,108 @@
+//! File: game_paths.rs
+//! Author: Wildflover
+//! Description: Labelled League of Legends installs (e.g. Live, PBE)
+//!              - Stored in game_paths.json next to game_path.txt
+//!              - Paths are resolved to the real Game folder before saving
+//!              - The same folder can only be registered under one label
+//! Language: Rust
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+// [CONST] Error code when a folder is already registered under another label
+pub const DUPLICATE_PATH: &str = "DUPLICATE_PATH";
+
+// [STRUCT] Single labelled game install
+#[derive(Serialize, Deserialize, Clone)]
+pub struct GamePathEntry {
+    pub label: String,
+    pub path: String,
+}
+
+// [FUNC] Get labelled game paths file location
+fn get_game_paths_file() -> PathBuf {
+    let app_data = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
+    app_data.join("Wildflover").join("game_paths.json")
+}
+
+// [FUNC] Load registered game paths - empty list when missing or unreadable
+fn load_game_paths() -> Vec<GamePathEntry> {
+    std::fs::read_to_string(get_game_paths_file())
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+// [FUNC] Persist registered game paths
+fn save_game_paths(entries: &[GamePathEntry]) -> Result<(), String> {
+    let file = get_game_paths_file();
+    if let Some(parent) = file.parent() {
+        std::fs::create_dir_all(parent).ok();
+    }
+
+    let json = serde_json::to_string_pretty(entries)
+        .map_err(|e| format!("Failed to serialize game paths: {}", e))?;
+    std::fs::write(&file, json).map_err(|e| format!("Failed to save game paths: {}", e))
+}
+
+// [FUNC] Comparison key for a resolved path - Windows paths are case-insensitive
+fn path_key(path: &str) -> String {
+    path.trim_end_matches(['\\', '/']).replace('/', "\\").to_lowercase()
+}
+
+// [COMMAND] List labelled game installs
+#[tauri::command]
+pub async fn list_game_paths() -> Vec<GamePathEntry> {
+    load_game_paths()
+}
+
+// [COMMAND] Register or update a labelled game install
+// Rejects a folder already registered under another label with DUPLICATE_PATH: <label>
+#[tauri::command]
+pub async fn add_game_path(label: String, path: String) -> Result<GamePathEntry, String> {
+    let label = label.trim().to_string();
+    if label.is_empty() {
+        return Err("Label cannot be empty".to_string());
+    }
+
+    let folder = crate::mod_manager::resolve_game_folder(Path::new(&path))
+        .ok_or_else(|| crate::mod_manager::game_folder_not_found(Path::new(&path)))?;
+    let real_path = crate::mod_manager::canonicalize_game_path(&folder.to_string_lossy())?;
+
+    let mut entries = load_game_paths();
+
+    // [DUPLICATE] Same install under a different label makes activations ambiguous
+    if let Some(existing) = entries
+        .iter()
+        .find(|e| e.label != label && path_key(&e.path) == path_key(&real_path))
+    {
+        println!("[GAME-PATHS] {} already registered as '{}'", real_path, existing.label);
+        return Err(format!("{}: {}", DUPLICATE_PATH, existing.label));
+    }
+
+    let entry = GamePathEntry { label: label.clone(), path: real_path };
+    match entries.iter_mut().find(|e| e.label == label) {
+        Some(existing) => *existing = entry.clone(),
+        None => entries.push(entry.clone()),
+    }
+    save_game_paths(&entries)?;
+
+    println!("[GAME-PATHS] Saved '{}' -> {}", entry.label, entry.path);
+    Ok(entry)
+}
+
+// [COMMAND] Remove a labelled game install - returns false when the label is unknown
+#[tauri::command]
+pub async fn remove_game_path(label: String) -> Result<bool, String> {
+    let mut entries = load_game_paths();
+    let before = entries.len();
+    entries.retain(|e| e.label != label);
+
+    if entries.len() == before {
+        return Ok(false);
+    }
+
+    save_game_paths(&entries)?;
+    println!("[GAME-PATHS] Removed '{}'", label);
+    Ok(true)
+}
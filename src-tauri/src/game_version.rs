@@ -0,0 +1,346 @@
+//! File: game_version.rs
+//! Author: Wildflover
+//! Description: League of Legends game version detection
+//!              - Reads file version from League of Legends.exe resources
+//!              - Tracks last seen patch to detect game updates
+//!              - Refreshes champion data when the patch changes
+//!              - Heuristic mod compatibility check against current patch
+//!              - Client locale detection from config or installed WADs
+//! Language: Rust
+
+use serde::Serialize;
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use zip::ZipArchive;
+
+// [CONST] VS_FIXEDFILEINFO signature (0xFEEF04BD little-endian)
+const VERSION_INFO_SIGNATURE: [u8; 4] = [0xBD, 0x04, 0xEF, 0xFE];
+
+// [CONST] Current WAD major version - older majors are from pre-2020 patches
+const CURRENT_WAD_MAJOR: u8 = 3;
+
+// [STRUCT] Game version check result
+#[derive(Serialize)]
+pub struct GameVersionInfo {
+    pub version: Option<String>,
+    pub patch: Option<String>,
+    pub previous_patch: Option<String>,
+    pub patch_changed: bool,
+}
+
+// [STRUCT] Mod compatibility report
+#[derive(Serialize)]
+pub struct CompatReport {
+    pub game_version: Option<String>,
+    pub game_updated_at: Option<String>,
+    pub mod_built_at: Option<String>,
+    pub wad_count: usize,
+    pub legacy_wad_count: usize,
+    pub outdated_wads: Vec<String>,
+    pub likely_outdated: bool,
+    pub warnings: Vec<String>,
+}
+
+// [FUNC] Get last seen game version file location
+fn get_game_version_file() -> PathBuf {
+    let app_data = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
+    app_data.join("Wildflover").join("game_version.txt")
+}
+
+// [FUNC] Read file version (e.g. "14.20.621.8418") from League of Legends.exe
+pub fn read_game_version(game_path: &str) -> Option<String> {
+    read_file_version(&PathBuf::from(game_path).join("League of Legends.exe"))
+}
+
+// [FUNC] Read PE file version from the VS_FIXEDFILEINFO resource of any executable/DLL
+pub fn read_file_version(path: &Path) -> Option<String> {
+    let bytes = std::fs::read(path).ok()?;
+
+    let offset = bytes.windows(4).position(|w| w == VERSION_INFO_SIGNATURE)?;
+    let fixed = bytes.get(offset + 8..offset + 16)?;
+
+    let ms = u32::from_le_bytes([fixed[0], fixed[1], fixed[2], fixed[3]]);
+    let ls = u32::from_le_bytes([fixed[4], fixed[5], fixed[6], fixed[7]]);
+
+    Some(format!("{}.{}.{}.{}", ms >> 16, ms & 0xFFFF, ls >> 16, ls & 0xFFFF))
+}
+
+// [FUNC] Reduce full version to patch (e.g. "14.20.621.8418" -> "14.20")
+pub fn patch_of(version: &str) -> String {
+    version.split('.').take(2).collect::<Vec<_>>().join(".")
+}
+
+// [FUNC] Get modification time of the game executable - approximates patch date
+fn game_updated_at(game_path: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    let exe_path = PathBuf::from(game_path).join("League of Legends.exe");
+    let modified = std::fs::metadata(exe_path).ok()?.modified().ok()?;
+    Some(chrono::DateTime::<chrono::Utc>::from(modified))
+}
+
+// [COMMAND] Detect game version and report whether the patch changed since last check
+#[tauri::command]
+pub async fn check_game_version(game_path: String) -> GameVersionInfo {
+    let version = read_game_version(&game_path);
+    let patch = version.as_deref().map(patch_of);
+
+    let version_file = get_game_version_file();
+    let previous_patch = std::fs::read_to_string(&version_file)
+        .ok()
+        .map(|s| patch_of(s.trim()))
+        .filter(|s| !s.is_empty());
+
+    let patch_changed = match (&patch, &previous_patch) {
+        (Some(current), Some(previous)) => current != previous,
+        (Some(_), None) => true,
+        _ => false,
+    };
+
+    if let Some(ref v) = version {
+        if let Some(parent) = version_file.parent() {
+            std::fs::create_dir_all(parent).ok();
+        }
+        std::fs::write(&version_file, v).ok();
+    }
+
+    println!("[GAME-VERSION] Version: {:?} | Previous patch: {:?} | Changed: {}",
+             version, previous_patch, patch_changed);
+
+    // [CHAMPION-DATA] New patches add skins - pull names for the matching Data Dragon version in the background
+    if let Some(current) = patch.clone() {
+        if patch_changed || !crate::champion_data::champion_data_matches_patch(&current) {
+            tauri::async_runtime::spawn(async move {
+                if let Err(e) = crate::champion_data::refresh_champion_data_for_patch(Some(&current)).await {
+                    println!("[GAME-VERSION] Champion data refresh failed: {}", e);
+                }
+            });
+        }
+    }
+
+    GameVersionInfo {
+        version,
+        patch,
+        previous_patch,
+        patch_changed,
+    }
+}
+
+// [FUNC] Inspect a WAD header - returns major version if it has the "RW" magic
+fn wad_major_version(header: &[u8]) -> Option<u8> {
+    if header.len() >= 4 && &header[0..2] == b"RW" {
+        Some(header[2])
+    } else {
+        None
+    }
+}
+
+// [FUNC] Check a single WAD entry and record findings in the report
+fn inspect_wad_entry(name: &str, header: &[u8], report: &mut CompatReport) {
+    let lower = name.to_lowercase();
+
+    if lower.ends_with(".wad.client") {
+        report.wad_count += 1;
+        match wad_major_version(header) {
+            Some(major) if major < CURRENT_WAD_MAJOR => {
+                report.outdated_wads.push(format!("{} (WAD v{})", name, major));
+            }
+            Some(_) => {}
+            None => report.warnings.push(format!("{} has no valid WAD header", name)),
+        }
+    } else if lower.ends_with(".wad") {
+        report.wad_count += 1;
+        report.legacy_wad_count += 1;
+    }
+}
+
+// [FUNC] Inspect a mod archive (.fantome/.zip)
+fn inspect_archive(path: &Path, report: &mut CompatReport) -> Result<Option<chrono::NaiveDateTime>, String> {
+    let file = File::open(path).map_err(|e| format!("Failed to open mod: {}", e))?;
+    let mut archive = ZipArchive::new(file).map_err(|e| format!("Invalid archive: {}", e))?;
+
+    let mut newest: Option<chrono::NaiveDateTime> = None;
+    let mut has_raw = false;
+
+    for i in 0..archive.len() {
+        let mut entry = match archive.by_index(i) {
+            Ok(entry) => entry,
+            Err(_) => continue,
+        };
+
+        let name = entry.name().to_string();
+
+        if let Some(dt) = entry.last_modified() {
+            let built = chrono::NaiveDate::from_ymd_opt(dt.year() as i32, dt.month() as u32, dt.day() as u32)
+                .and_then(|d| d.and_hms_opt(dt.hour() as u32, dt.minute() as u32, dt.second() as u32));
+            if let Some(built) = built {
+                if newest.map(|n| built > n).unwrap_or(true) {
+                    newest = Some(built);
+                }
+            }
+        }
+
+        if name.starts_with("RAW/") {
+            has_raw = true;
+        }
+
+        if name.starts_with("WAD/") && !entry.is_dir() {
+            let mut header = [0u8; 4];
+            let read = entry.read(&mut header).unwrap_or(0);
+            inspect_wad_entry(&name, &header[..read], report);
+        }
+    }
+
+    if has_raw {
+        report.warnings.push("Mod uses RAW/ loose files - older mod layout".to_string());
+    }
+
+    Ok(newest)
+}
+
+// [FUNC] Inspect an extracted mod folder
+fn inspect_folder(path: &Path, report: &mut CompatReport) -> Option<chrono::NaiveDateTime> {
+    let mut newest: Option<std::time::SystemTime> = None;
+
+    if let Ok(entries) = std::fs::read_dir(path.join("WAD")) {
+        for entry in entries.filter_map(|e| e.ok()) {
+            let entry_path = entry.path();
+            if !entry_path.is_file() {
+                continue;
+            }
+
+            if let Ok(modified) = entry.metadata().and_then(|m| m.modified()) {
+                if newest.map(|n| modified > n).unwrap_or(true) {
+                    newest = Some(modified);
+                }
+            }
+
+            let mut header = [0u8; 4];
+            let read = File::open(&entry_path)
+                .and_then(|mut f| f.read(&mut header))
+                .unwrap_or(0);
+            let name = format!("WAD/{}", entry.file_name().to_string_lossy());
+            inspect_wad_entry(&name, &header[..read], report);
+        }
+    }
+
+    if path.join("RAW").exists() {
+        report.warnings.push("Mod uses RAW/ loose files - older mod layout".to_string());
+    }
+
+    newest.map(|t| chrono::DateTime::<chrono::Utc>::from(t).naive_utc())
+}
+
+// [COMMAND] Heuristic check whether a custom mod was built for an older patch
+#[tauri::command]
+pub async fn check_mod_compatibility(path: String, game_path: String) -> Result<CompatReport, String> {
+    println!("[MOD-COMPAT] Checking: {}", path);
+
+    let mod_path = PathBuf::from(&path);
+    if !mod_path.exists() {
+        return Err(format!("Mod not found: {}", path));
+    }
+
+    let game_updated = game_updated_at(&game_path);
+
+    let mut report = CompatReport {
+        game_version: read_game_version(&game_path),
+        game_updated_at: game_updated.map(|t| t.to_rfc3339()),
+        mod_built_at: None,
+        wad_count: 0,
+        legacy_wad_count: 0,
+        outdated_wads: Vec::new(),
+        likely_outdated: false,
+        warnings: Vec::new(),
+    };
+
+    let built_at = if mod_path.is_dir() {
+        inspect_folder(&mod_path, &mut report)
+    } else {
+        inspect_archive(&mod_path, &mut report)?
+    };
+
+    report.mod_built_at = built_at.map(|t| t.and_utc().to_rfc3339());
+
+    if report.wad_count == 0 {
+        report.warnings.push("No WAD files found - mod may not change anything".to_string());
+    }
+    if report.legacy_wad_count > 0 {
+        report.warnings.push(format!("{} legacy .wad files (pre .wad.client format)", report.legacy_wad_count));
+    }
+    if !report.outdated_wads.is_empty() {
+        report.warnings.push(format!("{} WAD files use an outdated WAD version", report.outdated_wads.len()));
+    }
+
+    // [PATCH-DATE] Mod files older than the installed game build were made for an earlier patch
+    let built_before_patch = match (built_at, game_updated) {
+        (Some(built), Some(updated)) => built < updated.naive_utc(),
+        _ => false,
+    };
+    if built_before_patch {
+        report.warnings.push("Mod was built before the current game patch".to_string());
+    }
+
+    report.likely_outdated = report.legacy_wad_count > 0
+        || !report.outdated_wads.is_empty()
+        || built_before_patch;
+
+    println!("[MOD-COMPAT] {} WADs, likely outdated: {}, warnings: {}",
+             report.wad_count, report.likely_outdated, report.warnings.len());
+
+    Ok(report)
+}
+
+// [FUNC] Locale codes look like "en_US" - two lowercase, underscore, two uppercase
+fn is_locale_code(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    bytes.len() == 5
+        && bytes[0].is_ascii_lowercase()
+        && bytes[1].is_ascii_lowercase()
+        && bytes[2] == b'_'
+        && bytes[3].is_ascii_uppercase()
+        && bytes[4].is_ascii_uppercase()
+}
+
+// [FUNC] Read "locale: xx_XX" from LeagueClientSettings.yaml
+fn locale_from_client_settings(path: &Path) -> Option<String> {
+    let content = std::fs::read_to_string(path).ok()?;
+    content.lines().find_map(|line| {
+        let value = line.trim().strip_prefix("locale:")?;
+        let value = value.trim().trim_matches('"').trim_matches('\'');
+        is_locale_code(value).then(|| value.to_string())
+    })
+}
+
+// [FUNC] Infer locale from localized champion WADs (e.g. "Ahri.en_US.wad.client")
+fn locale_from_installed_wads(game_dir: &Path) -> Option<String> {
+    let champions_dir = game_dir.join("DATA").join("FINAL").join("Champions");
+    let entries = std::fs::read_dir(champions_dir).ok()?;
+
+    entries.filter_map(|e| e.ok()).find_map(|entry| {
+        let name = entry.file_name().to_string_lossy().to_string();
+        let base = name.strip_suffix(".wad.client")?;
+        let locale = base.rsplit('.').next()?;
+        (base.contains('.') && is_locale_code(locale)).then(|| locale.to_string())
+    })
+}
+
+// [COMMAND] Detect the League client locale so locale filters default to the player's language
+#[tauri::command]
+pub async fn detect_game_locale(game_path: String) -> Option<String> {
+    let game_dir = PathBuf::from(&game_path);
+
+    // Game path usually points at ".../League of Legends/Game" - client config sits one level up
+    let candidates = [
+        game_dir.join("Config").join("LeagueClientSettings.yaml"),
+        game_dir.parent().map(|p| p.join("Config").join("LeagueClientSettings.yaml")).unwrap_or_default(),
+    ];
+
+    let locale = candidates
+        .iter()
+        .filter(|p| p.is_file())
+        .find_map(|p| locale_from_client_settings(p))
+        .or_else(|| locale_from_installed_wads(&game_dir));
+
+    println!("[GAME-LOCALE] Detected locale: {:?}", locale);
+    locale
+}
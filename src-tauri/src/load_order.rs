@@ -0,0 +1,131 @@
+//! File: load_order.rs
+//! Author: Wildflover
+//! Description: Named mod load order presets stored in load_orders.json
+//!              - Save curated --mods orderings under a name
+//!              - Applying a preset makes activation follow that order
+//!              - Conflict resolutions still win over the preset
+//!              - Clearing or changing the applied preset restores selection order
+//! Language: Rust
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+// [STATE] Serializes read-modify-write of load_orders.json
+static LOAD_ORDER_LOCK: Mutex<()> = Mutex::new(());
+
+// [STRUCT] Persisted presets and the one currently applied
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+struct LoadOrderStore {
+    presets: BTreeMap<String, Vec<String>>,
+    active: Option<String>,
+}
+
+// [FUNC] Get load order presets file location
+fn get_load_orders_file() -> PathBuf {
+    let app_data = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
+    app_data.join("Wildflover").join("load_orders.json")
+}
+
+// [FUNC] Load presets - empty store when missing or unreadable
+fn load_store() -> LoadOrderStore {
+    std::fs::read_to_string(get_load_orders_file())
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+// [FUNC] Persist presets
+fn save_store(store: &LoadOrderStore) -> Result<(), String> {
+    let file = get_load_orders_file();
+    if let Some(parent) = file.parent() {
+        std::fs::create_dir_all(parent).ok();
+    }
+
+    let json = serde_json::to_string_pretty(store)
+        .map_err(|e| format!("Failed to serialize load orders: {}", e))?;
+    std::fs::write(&file, json).map_err(|e| format!("Failed to save load orders: {}", e))
+}
+
+// [FUNC] Mod order of the applied preset, if any
+pub fn active_load_order() -> Option<Vec<String>> {
+    let store = load_store();
+    store.active.and_then(|name| store.presets.get(&name).cloned())
+}
+
+// [COMMAND] Save a named ordering of mod names - later entries override earlier ones
+#[tauri::command]
+pub async fn save_load_order(name: String, order: Vec<String>) -> Result<(), String> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err("Preset name cannot be empty".to_string());
+    }
+
+    // Keep first occurrence only - a mod can hold one position
+    let mut seen = std::collections::HashSet::new();
+    let order: Vec<String> = order.into_iter().filter(|m| seen.insert(m.clone())).collect();
+
+    let _guard = LOAD_ORDER_LOCK.lock().map_err(|_| "Load order lock poisoned".to_string())?;
+    let mut store = load_store();
+
+    // Overwriting the applied preset un-applies it - the new order must be applied explicitly
+    if store.active.as_deref() == Some(name.as_str()) {
+        store.active = None;
+    }
+
+    println!("[LOAD-ORDER] Saved '{}' ({} mods)", name, order.len());
+    store.presets.insert(name, order);
+    save_store(&store)
+}
+
+// [COMMAND] Delete a preset - returns false when the name is unknown
+#[tauri::command]
+pub async fn delete_load_order(name: String) -> Result<bool, String> {
+    let _guard = LOAD_ORDER_LOCK.lock().map_err(|_| "Load order lock poisoned".to_string())?;
+    let mut store = load_store();
+
+    if store.presets.remove(&name).is_none() {
+        return Ok(false);
+    }
+    if store.active.as_deref() == Some(name.as_str()) {
+        store.active = None;
+    }
+    save_store(&store)?;
+
+    println!("[LOAD-ORDER] Deleted '{}'", name);
+    Ok(true)
+}
+
+// [COMMAND] Stop applying a preset - activations follow selection order again
+#[tauri::command]
+pub async fn clear_load_order() -> Result<(), String> {
+    let _guard = LOAD_ORDER_LOCK.lock().map_err(|_| "Load order lock poisoned".to_string())?;
+    let mut store = load_store();
+
+    if let Some(name) = store.active.take() {
+        save_store(&store)?;
+        println!("[LOAD-ORDER] Cleared applied preset '{}'", name);
+    }
+    Ok(())
+}
+
+// [COMMAND] Apply a saved preset to the next activations - returns its mod order
+#[tauri::command]
+pub async fn apply_load_order(name: String) -> Result<Vec<String>, String> {
+    let _guard = LOAD_ORDER_LOCK.lock().map_err(|_| "Load order lock poisoned".to_string())?;
+    let mut store = load_store();
+
+    let order = store
+        .presets
+        .get(&name)
+        .cloned()
+        .ok_or_else(|| format!("Load order preset not found: {}", name))?;
+
+    store.active = Some(name.clone());
+    save_store(&store)?;
+
+    println!("[LOAD-ORDER] Applied '{}' ({} mods)", name, order.len());
+    Ok(order)
+}
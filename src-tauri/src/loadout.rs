@@ -0,0 +1,252 @@
+//! File: loadout.rs
+//! Author: Wildflover
+//! Description: Loadout file import/export (.wildflover-loadout.json)
+//!              - Versioned file format listing skins and marketplace mods
+//!              - Bulk import resolves entries via the existing download commands
+//! Language: Rust
+
+use serde::{Deserialize, Serialize};
+use crate::marketplace::download_marketplace_mod;
+use crate::mod_manager::{download_skin, SkinDownloadRequest};
+
+// [CONST] Loadout file identity - bump version on breaking format changes
+const LOADOUT_FORMAT: &str = "wildflover-loadout";
+const LOADOUT_VERSION: u32 = 1;
+const LOADOUT_EXTENSION: &str = "wildflover-loadout.json";
+
+// [STRUCT] Skin entry in a loadout
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LoadoutSkin {
+    pub champion_id: i32,
+    pub skin_id: i32,
+    #[serde(default)]
+    pub chroma_id: Option<i32>,
+    #[serde(default)]
+    pub form_id: Option<i32>,
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+// [STRUCT] Marketplace mod entry in a loadout
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LoadoutMarketplaceMod {
+    pub mod_id: String,
+    pub download_url: String,
+    pub name: String,
+}
+
+// [STRUCT] Loadout file contents
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoadoutFile {
+    pub format: String,
+    pub version: u32,
+    #[serde(default)]
+    pub skins: Vec<LoadoutSkin>,
+    #[serde(default)]
+    pub marketplace_mods: Vec<LoadoutMarketplaceMod>,
+}
+
+// [STRUCT] Result of resolving a single loadout entry
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoadoutEntryResult {
+    pub label: String,
+    pub success: bool,
+    pub path: Option<String>,
+    pub error: Option<String>,
+}
+
+// [STRUCT] Loadout import summary
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportSummary {
+    pub success: bool,
+    pub cancelled: bool,
+    pub error: Option<String>,
+    pub imported: usize,
+    pub failed: usize,
+    pub entries: Vec<LoadoutEntryResult>,
+}
+
+// [STRUCT] Loadout export result
+#[derive(Serialize)]
+pub struct ExportResult {
+    pub success: bool,
+    pub path: Option<String>,
+    pub cancelled: bool,
+    pub error: Option<String>,
+}
+
+impl ImportSummary {
+    fn rejected(error: String) -> Self {
+        ImportSummary {
+            success: false,
+            cancelled: false,
+            error: Some(error),
+            imported: 0,
+            failed: 0,
+            entries: Vec::new(),
+        }
+    }
+}
+
+// [FUNC] Parse and validate loadout file contents
+fn parse_loadout(content: &str) -> Result<LoadoutFile, String> {
+    let loadout: LoadoutFile = serde_json::from_str(content)
+        .map_err(|e| format!("Not a valid loadout file: {}", e))?;
+
+    if loadout.format != LOADOUT_FORMAT {
+        return Err(format!("Unknown file format '{}' (expected '{}')", loadout.format, LOADOUT_FORMAT));
+    }
+
+    if loadout.version == 0 || loadout.version > LOADOUT_VERSION {
+        return Err(format!(
+            "Loadout version {} is not supported (this version of Wildflover supports up to {})",
+            loadout.version, LOADOUT_VERSION
+        ));
+    }
+
+    Ok(loadout)
+}
+
+// [COMMAND] Save a loadout to a .wildflover-loadout.json file chosen by the user
+#[tauri::command]
+pub async fn export_loadout_file(
+    skins: Vec<LoadoutSkin>,
+    marketplace_mods: Vec<LoadoutMarketplaceMod>,
+) -> ExportResult {
+    println!("[LOADOUT-EXPORT] Exporting {} skins, {} marketplace mods", skins.len(), marketplace_mods.len());
+
+    let dialog = rfd::FileDialog::new()
+        .add_filter("Wildflover Loadout", &["json"])
+        .set_title("Export Loadout")
+        .set_file_name(format!("loadout.{}", LOADOUT_EXTENSION))
+        .save_file();
+
+    let path = match dialog {
+        Some(path) => path,
+        None => {
+            println!("[LOADOUT-EXPORT] Save dialog cancelled");
+            return ExportResult { success: false, path: None, cancelled: true, error: None };
+        }
+    };
+
+    let loadout = LoadoutFile {
+        format: LOADOUT_FORMAT.to_string(),
+        version: LOADOUT_VERSION,
+        skins,
+        marketplace_mods,
+    };
+
+    let written = serde_json::to_string_pretty(&loadout)
+        .map_err(|e| format!("Failed to serialize loadout: {}", e))
+        .and_then(|json| std::fs::write(&path, json).map_err(|e| format!("Failed to write loadout: {}", e)));
+
+    match written {
+        Ok(()) => {
+            println!("[LOADOUT-EXPORT] Saved: {:?}", path);
+            ExportResult {
+                success: true,
+                path: Some(path.to_string_lossy().to_string()),
+                cancelled: false,
+                error: None,
+            }
+        }
+        Err(e) => {
+            println!("[LOADOUT-EXPORT] ERROR: {}", e);
+            ExportResult { success: false, path: None, cancelled: false, error: Some(e) }
+        }
+    }
+}
+
+// [COMMAND] Pick a .wildflover-loadout.json file and download every entry it lists
+#[tauri::command]
+pub async fn import_loadout_file(app: tauri::AppHandle) -> ImportSummary {
+    println!("[LOADOUT-IMPORT] Opening file dialog for loadout...");
+
+    let dialog = rfd::FileDialog::new()
+        .add_filter("Wildflover Loadout", &["json"])
+        .set_title("Import Loadout")
+        .pick_file();
+
+    let path = match dialog {
+        Some(path) => path,
+        None => {
+            println!("[LOADOUT-IMPORT] File dialog cancelled");
+            return ImportSummary {
+                success: false,
+                cancelled: true,
+                error: None,
+                imported: 0,
+                failed: 0,
+                entries: Vec::new(),
+            };
+        }
+    };
+
+    let content = match std::fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) => return ImportSummary::rejected(format!("Failed to read loadout: {}", e)),
+    };
+
+    let loadout = match parse_loadout(&content) {
+        Ok(loadout) => loadout,
+        Err(e) => {
+            println!("[LOADOUT-IMPORT] Rejected {:?}: {}", path, e);
+            return ImportSummary::rejected(e);
+        }
+    };
+
+    println!("[LOADOUT-IMPORT] Loadout v{}: {} skins, {} marketplace mods",
+             loadout.version, loadout.skins.len(), loadout.marketplace_mods.len());
+
+    let mut entries: Vec<LoadoutEntryResult> = Vec::new();
+
+    // [RESOLVE] Skins via download_skin
+    for skin in loadout.skins {
+        let label = skin.name.clone().unwrap_or_else(|| format!("{}_{}", skin.champion_id, skin.skin_id));
+        let result = download_skin(app.clone(), SkinDownloadRequest {
+            champion_id: skin.champion_id,
+            skin_id: skin.skin_id,
+            chroma_id: skin.chroma_id,
+            form_id: skin.form_id,
+            expected_sha256: None,
+        }).await;
+
+        entries.push(LoadoutEntryResult {
+            label,
+            success: result.success,
+            path: result.path,
+            error: result.error,
+        });
+    }
+
+    // [RESOLVE] Marketplace mods via download_marketplace_mod
+    for item in loadout.marketplace_mods {
+        let result = download_marketplace_mod(app.clone(), item.mod_id, item.download_url, item.name.clone()).await;
+
+        entries.push(LoadoutEntryResult {
+            label: item.name,
+            success: result.success,
+            path: result.local_path,
+            error: result.error,
+        });
+    }
+
+    let imported = entries.iter().filter(|e| e.success).count();
+    let failed = entries.len() - imported;
+
+    println!("[LOADOUT-IMPORT] Complete: {} imported, {} failed", imported, failed);
+
+    ImportSummary {
+        success: failed == 0,
+        cancelled: false,
+        error: None,
+        imported,
+        failed,
+        entries,
+    }
+}
@@ -0,0 +1,221 @@
+//! File: logs.rs
+//! Author: Wildflover
+//! Description: Log file management for the Wildflover logs folder
+//!              - List log files with size and modified time
+//!              - Clear rotated logs while keeping the current one
+//!              - Tail recent lines for the in-app console
+//!              - File sink mirroring every println! line, rotated by size
+//! Language: Rust
+
+use serde::Serialize;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+// [CONST] Active log file name - rotated files sit next to it
+pub const CURRENT_LOG_FILE: &str = "wildflover.log";
+
+// [CONST] Rotate once the current log passes this size, keeping this many files in total
+const MAX_LOG_SIZE: u64 = 5 * 1024 * 1024;
+const MAX_LOG_FILES: usize = 3;
+
+// [STATE] Open log file and its current size - None until init_file_logging ran
+static LOG_SINK: Mutex<Option<LogSink>> = Mutex::new(None);
+
+// [STRUCT] Append handle for the current log file
+struct LogSink {
+    file: File,
+    size: u64,
+}
+
+// [CONST] Upper bound for tail_log to keep the IPC payload small
+const MAX_TAIL_LINES: usize = 2000;
+
+// [STRUCT] Log file entry
+#[derive(Serialize)]
+pub struct LogFileInfo {
+    pub name: String,
+    pub path: String,
+    pub size: u64,
+    pub modified: u64,
+    pub is_current: bool,
+}
+
+// [FUNC] Get logs directory
+pub fn get_logs_directory() -> PathBuf {
+    let app_data = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
+    app_data.join("Wildflover").join("logs")
+}
+
+// [FUNC] Open the current log for appending - called once from main before anything is logged
+// NOTE: Never println! in here, the println! macro writes back into this module
+pub fn init_file_logging() {
+    let logs_dir = get_logs_directory();
+    if std::fs::create_dir_all(&logs_dir).is_err() {
+        return;
+    }
+
+    let path = logs_dir.join(CURRENT_LOG_FILE);
+    let file = match std::fs::OpenOptions::new().create(true).append(true).open(&path) {
+        Ok(file) => file,
+        Err(e) => {
+            eprintln!("[LOGS] WARN: Failed to open log file: {}", e);
+            return;
+        }
+    };
+    let size = file.metadata().map(|m| m.len()).unwrap_or(0);
+
+    if let Ok(mut sink) = LOG_SINK.lock() {
+        *sink = Some(LogSink { file, size });
+    }
+}
+
+// [FUNC] Shift wildflover.log -> wildflover.log.1 -> ... and drop the oldest
+fn rotate_logs() -> Option<File> {
+    let logs_dir = get_logs_directory();
+    let rotated = |index: usize| logs_dir.join(format!("{}.{}", CURRENT_LOG_FILE, index));
+
+    std::fs::remove_file(rotated(MAX_LOG_FILES - 1)).ok();
+    for index in (1..MAX_LOG_FILES - 1).rev() {
+        std::fs::rename(rotated(index), rotated(index + 1)).ok();
+    }
+    std::fs::rename(logs_dir.join(CURRENT_LOG_FILE), rotated(1)).ok();
+
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(logs_dir.join(CURRENT_LOG_FILE))
+        .ok()
+}
+
+// [FUNC] Append one line with a timestamp - silently dropped when file logging is not set up
+pub fn write_line(line: &str) {
+    let mut guard = match LOG_SINK.lock() {
+        Ok(guard) => guard,
+        Err(_) => return,
+    };
+    let sink = match guard.as_mut() {
+        Some(sink) => sink,
+        None => return,
+    };
+
+    if sink.size >= MAX_LOG_SIZE {
+        match rotate_logs() {
+            Some(file) => *sink = LogSink { file, size: 0 },
+            None => {
+                *guard = None;
+                return;
+            }
+        }
+    }
+
+    let entry = format!("{} {}\n", chrono::Local::now().format("%Y-%m-%d %H:%M:%S%.3f"), line);
+    if let Some(sink) = guard.as_mut() {
+        if sink.file.write_all(entry.as_bytes()).is_ok() {
+            sink.size += entry.len() as u64;
+        }
+    }
+}
+
+// [COMMAND] Absolute path of the current log file - for bug reports
+#[tauri::command]
+pub async fn get_log_path() -> String {
+    get_logs_directory().join(CURRENT_LOG_FILE).to_string_lossy().to_string()
+}
+
+// [COMMAND] List log files, newest first
+#[tauri::command]
+pub async fn list_log_files() -> Vec<LogFileInfo> {
+    let mut files: Vec<LogFileInfo> = Vec::new();
+
+    if let Ok(entries) = std::fs::read_dir(get_logs_directory()) {
+        for entry in entries.filter_map(|e| e.ok()) {
+            let metadata = match entry.metadata() {
+                Ok(m) if m.is_file() => m,
+                _ => continue,
+            };
+
+            let name = entry.file_name().to_string_lossy().to_string();
+            let modified = metadata
+                .modified()
+                .ok()
+                .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+
+            files.push(LogFileInfo {
+                is_current: name == CURRENT_LOG_FILE,
+                path: entry.path().to_string_lossy().to_string(),
+                size: metadata.len(),
+                modified,
+                name,
+            });
+        }
+    }
+
+    files.sort_by_key(|f| std::cmp::Reverse(f.modified));
+    files
+}
+
+// [COMMAND] Delete all log files except the current one - returns number deleted
+#[tauri::command]
+pub async fn clear_logs() -> usize {
+    let mut deleted = 0;
+
+    for file in list_log_files().await {
+        if file.is_current {
+            continue;
+        }
+        match std::fs::remove_file(&file.path) {
+            Ok(()) => deleted += 1,
+            Err(e) => println!("[LOGS] WARN: Failed to delete {}: {}", file.name, e),
+        }
+    }
+
+    println!("[LOGS] Cleared {} log files", deleted);
+    deleted
+}
+
+// [COMMAND] Read the last N lines of the current log
+#[tauri::command]
+pub async fn tail_log(lines: usize) -> Vec<String> {
+    let lines = lines.min(MAX_TAIL_LINES);
+    if lines == 0 {
+        return Vec::new();
+    }
+
+    let log_path = get_logs_directory().join(CURRENT_LOG_FILE);
+    let mut file = match std::fs::File::open(&log_path) {
+        Ok(file) => file,
+        Err(_) => return Vec::new(),
+    };
+
+    // [SEEK] Read backwards in chunks until enough newlines are found - avoids loading large logs
+    let file_len = file.metadata().map(|m| m.len()).unwrap_or(0);
+    let chunk_size: u64 = 64 * 1024;
+    let mut start = file_len;
+    let mut buffer: Vec<u8> = Vec::new();
+
+    while start > 0 {
+        let read_size = chunk_size.min(start);
+        start -= read_size;
+
+        let mut chunk = vec![0u8; read_size as usize];
+        if file.seek(SeekFrom::Start(start)).is_err() || file.read_exact(&mut chunk).is_err() {
+            break;
+        }
+        chunk.extend_from_slice(&buffer);
+        buffer = chunk;
+
+        if buffer.iter().filter(|&&b| b == b'\n').count() > lines {
+            break;
+        }
+    }
+
+    let text = String::from_utf8_lossy(&buffer);
+    let all: Vec<&str> = text.lines().collect();
+    let skip = all.len().saturating_sub(lines);
+
+    all[skip..].iter().map(|l| l.to_string()).collect()
+}
@@ -10,6 +10,19 @@
 
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+// [LOGGING] Shadows std println! for every module below - console output is mirrored to the log file
+// Release builds have no console, so the log file is the only place these lines survive
+macro_rules! println {
+    () => {
+        println!("")
+    };
+    ($($arg:tt)*) => {{
+        let line = format!($($arg)*);
+        std::println!("{}", line);
+        crate::logs::write_line(&line);
+    }};
+}
+
 mod discord;
 mod discord_rpc;
 mod webhook;
@@ -21,6 +34,19 @@ mod marketplace_like;
 mod marketplace_upload;
 mod marketplace_download_count;
 mod marketplace_update;
+mod game_version;
+mod mod_cache;
+mod settings;
+mod loadout;
+mod logs;
+mod mod_archive;
+mod download_limiter;
+mod champion_data;
+mod file_hash;
+mod config_backup;
+mod game_paths;
+mod load_order;
+mod mod_profiles;
 
 use std::sync::atomic::{AtomicBool, Ordering};
 use tauri::{
@@ -30,18 +56,31 @@ use tauri::{
 };
 use discord::{discord_exchange_code, discord_refresh_token, discord_revoke_token};
 use discord_rpc::{
-    set_rpc_enabled, is_rpc_enabled, update_activity, 
-    clear_activity, get_start_timestamp, reset_timestamp
+    set_rpc_enabled, is_rpc_enabled, set_rpc_buttons, update_activity, 
+    clear_activity, get_start_timestamp, reset_timestamp, validate_rpc_image_key
 };
-use webhook::{send_login_webhook, send_logout_webhook};
-use mod_manager::{download_skin, activate_mods, detect_game_path, set_game_path, browse_game_path, clear_game_path, cleanup_overlay, stop_overlay, is_overlay_running, clear_mods_cache, get_cache_info, clear_cache, delete_cache_file, delete_custom_mod_cache, run_diagnostic};
+use webhook::{send_login_webhook, send_logout_webhook, set_webhook_config};
+use mod_manager::{download_skin, download_skins_batch, cancel_download, check_skin_repo, get_skin_repo, set_skin_repo, get_skin_mirrors, set_skin_mirrors, activate_mods, retry_failed_mods, get_last_activation, detect_game_path, set_game_path, browse_game_path, repair_game_path_config, clear_game_path, cleanup_overlay, stop_overlay, pause_overlay, resume_overlay, is_overlay_paused, is_overlay_running, get_injected_mods, is_vanguard_active, reconcile_overlay_state, ensure_overlay_writable, get_mod_states, clean_temp_folders, set_custom_mod_champion, clear_mods_cache, get_cache_info, clear_cache, delete_cache_file, delete_custom_mod_cache, detect_name_collisions, repair_name_collisions, find_missing_active_mods, run_diagnostic, get_version_info, verify_managers_complete, check_managers_version, update_managers_from_bundle, test_activation_pipeline};
 
-use marketplace::{download_marketplace_mod, clear_marketplace_cache, fetch_marketplace_catalog, delete_marketplace_mod_cache, fetch_mod_preview};
-use marketplace_like::like_marketplace_mod;
-use marketplace_upload::upload_marketplace_mod;
+use marketplace::{download_marketplace_mod, clear_marketplace_cache, fetch_marketplace_catalog, set_catalog_auto_refresh, set_user_agent, delete_marketplace_mod_cache, is_marketplace_mod_installed, fetch_mod_preview, fetch_previews_batch, fetch_mod_info_json, fetch_mod_latest_change, fetch_mods_detail, fetch_catalog_ids, fetch_mod_likers, get_marketplace_summary, get_marketplace_latency};
+use marketplace_like::{like_marketplace_mod, queue_like};
+use marketplace_upload::{upload_marketplace_mod, validate_upload_metadata};
 use marketplace_delete::delete_marketplace_mod;
 use marketplace_download_count::increment_download_count;
 use marketplace_update::update_marketplace_mod;
+use game_version::{check_game_version, check_mod_compatibility, detect_game_locale};
+use champion_data::{refresh_champion_data, get_champion_data_version};
+use mod_cache::{find_duplicate_cache_files, dedupe_cache_files, set_auto_cache_cleanup, find_orphaned_archives, clean_orphaned_archives, search_cache, verify_cache_integrity, list_cached_champions, list_installed_mods};
+use settings::{get_import_concurrency, set_import_concurrency, get_mkoverlay_retries, set_mkoverlay_retries, get_overlay_retry_config, set_overlay_retry_config, get_overlay_verify_delay, set_overlay_verify_delay, set_strict_size_verification, get_extraction_filters, set_extraction_filters};
+use loadout::{export_loadout_file, import_loadout_file};
+use logs::{list_log_files, clear_logs, tail_log, get_log_path};
+use mod_archive::{analyze_mod_contents, is_valid_archive, validate_mod_archive, read_mod_info, summarize_activation, verify_mod_champion, preview_game_writes};
+use download_limiter::{get_max_concurrent_downloads, set_max_concurrent_downloads};
+use file_hash::get_file_hash;
+use config_backup::{export_config, import_config};
+use game_paths::{list_game_paths, add_game_path, remove_game_path};
+use load_order::{save_load_order, apply_load_order, delete_load_order, clear_load_order};
+use mod_profiles::{save_profile, load_profile, list_profiles};
 use serde::Serialize;
 
 // [STATE] Global flag for minimize to tray setting
@@ -81,12 +120,24 @@ fn open_folder_in_explorer(path: String) -> Result<(), String> {
     Ok(())
 }
 
+// [COMMAND] Open the logs folder so users can attach wildflover.log to bug reports
+#[tauri::command]
+fn open_log_folder() -> Result<(), String> {
+    let logs_dir = logs::get_logs_directory();
+    std::fs::create_dir_all(&logs_dir).map_err(|e| format!("Failed to create logs folder: {}", e))?;
+    open_folder_in_explorer(logs_dir.to_string_lossy().to_string())
+}
+
 // [STRUCT] File info for custom mod selection
 #[derive(Serialize)]
 struct FileInfo {
     name: String,
     path: String,
     size: u64,
+    valid: bool,
+    reason: Option<String>,
+    is_dir: bool,
+    wad_count: Option<usize>,
 }
 
 // [STRUCT] File selection result
@@ -96,6 +147,36 @@ struct FileSelectionResult {
     files: Vec<FileInfo>,
 }
 
+// [STRUCT] Which build-time integrations still hold placeholder credentials
+#[derive(Serialize)]
+struct IntegrationStatus {
+    github: bool,
+    discord_oauth: bool,
+    webhook: bool,
+    unconfigured: Vec<String>,
+}
+
+// [FUNC] Check shipped placeholder credentials - forks often forget to replace them
+fn integration_status() -> IntegrationStatus {
+    let github = marketplace::token_configured();
+    let discord_oauth = discord::oauth_configured();
+    let webhook = webhook::webhook_configured();
+
+    let unconfigured = [("github", github), ("discord_oauth", discord_oauth), ("webhook", webhook)]
+        .iter()
+        .filter(|(_, configured)| !configured)
+        .map(|(name, _)| name.to_string())
+        .collect();
+
+    IntegrationStatus { github, discord_oauth, webhook, unconfigured }
+}
+
+// [COMMAND] Report unconfigured integrations so the UI can disable those features
+#[tauri::command]
+fn get_integration_status() -> IntegrationStatus {
+    integration_status()
+}
+
 // [COMMAND] Update minimize to tray setting from frontend
 #[tauri::command]
 fn set_minimize_to_tray(enabled: bool) {
@@ -117,9 +198,9 @@ async fn select_custom_files() -> FileSelectionResult {
     println!("[CUSTOMS-SELECT] Opening file dialog for custom mods...");
     
     let dialog = rfd::FileDialog::new()
-        .add_filter("Custom Mods", &["wad", "zip", "fantome"])
+        .add_filter("Custom Mods", &["wad", "client", "zip", "fantome"])
         .add_filter("Fantome Files", &["fantome"])
-        .add_filter("WAD Files", &["wad"])
+        .add_filter("WAD Files", &["wad", "client"])
         .add_filter("ZIP Files", &["zip"])
         .set_title("Select Custom Mod Files")
         .pick_files();
@@ -149,12 +230,19 @@ async fn select_custom_files() -> FileSelectionResult {
                     name
                 };
                 
-                println!("[CUSTOMS-SELECT] Selected: {} ({} bytes)", final_name, size);
+                // [VALIDATE] Flag non-mod files now instead of failing at activation
+                let validation = mod_archive::validate_mod_file(&path);
+                
+                println!("[CUSTOMS-SELECT] Selected: {} ({} bytes) valid: {}", final_name, size, validation.is_ok());
                 
                 files.push(FileInfo {
                     name: final_name,
                     path: path_str,
                     size,
+                    valid: validation.is_ok(),
+                    reason: validation.err(),
+                    is_dir: false,
+                    wad_count: None,
                 });
             }
             
@@ -202,7 +290,7 @@ async fn select_preview_image() -> FileSelectionResult {
             
             FileSelectionResult {
                 success: true,
-                files: vec![FileInfo { name, path: path_str, size }],
+                files: vec![FileInfo { name, path: path_str, size, valid: true, reason: None, is_dir: false, wad_count: None }],
             }
         }
         None => {
@@ -264,7 +352,7 @@ async fn select_preview_image_with_data() -> PreviewSelectionResult {
             
             PreviewSelectionResult {
                 success: true,
-                files: vec![FileInfo { name, path: path_str, size }],
+                files: vec![FileInfo { name, path: path_str, size, valid: true, reason: None, is_dir: false, wad_count: None }],
                 base64: base64_data,
             }
         }
@@ -295,20 +383,35 @@ async fn get_file_info(path: String) -> Result<FileInfo, String> {
         .map(|n| n.to_string_lossy().to_string())
         .unwrap_or_else(|| "unknown".to_string());
     
-    let size = std::fs::metadata(&path)
-        .map(|m| m.len())
-        .map_err(|e| format!("Failed to get file metadata: {}", e))?;
+    // [FOLDER] Dropped extracted mods report their recursive size and WAD count
+    let is_dir = file_path.is_dir();
+    let size = if is_dir {
+        mod_manager::calculate_dir_size(&file_path.to_path_buf())
+    } else {
+        std::fs::metadata(&path).map(|m| m.len())
+    }
+    .map_err(|e| format!("Failed to get file metadata: {}", e))?;
+    let wad_count = is_dir.then(|| mod_archive::count_wad_files(file_path));
+    
+    // [VALIDATE] Dropped files get the same mod check as dialog selections
+    let validation = mod_archive::validate_mod_file(file_path);
     
-    println!("[FILE-INFO] Retrieved info for: {} ({} bytes)", name, size);
+    println!("[FILE-INFO] Retrieved info for: {} ({} bytes) valid: {} folder: {} wads: {:?}",
+             name, size, validation.is_ok(), is_dir, wad_count);
     
     Ok(FileInfo {
         name,
         path,
         size,
+        valid: validation.is_ok(),
+        reason: validation.err(),
+        is_dir,
+        wad_count,
     })
 }
 
 fn main() {
+    logs::init_file_logging();
     println!("[SYSTEM-INIT] Wildflover LoL Skin Changer v1.0.0");
     println!("[SYSTEM-INIT] Initializing Tauri runtime with tray support...");
 
@@ -327,37 +430,136 @@ fn main() {
             discord_revoke_token,
             set_rpc_enabled,
             is_rpc_enabled,
+            set_rpc_buttons,
             update_activity,
             clear_activity,
             get_start_timestamp,
             reset_timestamp,
+            validate_rpc_image_key,
             send_login_webhook,
             send_logout_webhook,
+            set_webhook_config,
             download_skin,
+            download_skins_batch,
+            cancel_download,
+            check_skin_repo,
+            get_skin_repo,
+            set_skin_repo,
+            get_skin_mirrors,
+            set_skin_mirrors,
             activate_mods,
+            retry_failed_mods,
+            get_last_activation,
+            save_load_order,
+            apply_load_order,
+            delete_load_order,
+            clear_load_order,
+            save_profile,
+            load_profile,
+            list_profiles,
             detect_game_path,
             set_game_path,
             browse_game_path,
+            repair_game_path_config,
             clear_game_path,
+            list_game_paths,
+            add_game_path,
+            remove_game_path,
             cleanup_overlay,
             stop_overlay,
+            pause_overlay,
+            resume_overlay,
+            is_overlay_paused,
             is_overlay_running,
+            get_injected_mods,
+            is_vanguard_active,
+            reconcile_overlay_state,
+            ensure_overlay_writable,
+            get_mod_states,
+            clean_temp_folders,
+            set_custom_mod_champion,
             clear_mods_cache,
             get_cache_info,
             clear_cache,
             delete_cache_file,
             delete_custom_mod_cache,
+            detect_name_collisions,
+            repair_name_collisions,
+            find_missing_active_mods,
             run_diagnostic,
+            get_version_info,
+            verify_managers_complete,
+            check_managers_version,
+            update_managers_from_bundle,
+            test_activation_pipeline,
             download_marketplace_mod,
             upload_marketplace_mod,
+            validate_upload_metadata,
             clear_marketplace_cache,
             delete_marketplace_mod_cache,
+            is_marketplace_mod_installed,
             like_marketplace_mod,
+            queue_like,
             fetch_marketplace_catalog,
+            set_catalog_auto_refresh,
+            set_user_agent,
             fetch_mod_preview,
+            fetch_previews_batch,
+            fetch_mod_info_json,
+            fetch_mod_latest_change,
+            fetch_mods_detail,
+            fetch_catalog_ids,
+            fetch_mod_likers,
+            get_marketplace_summary,
+            get_marketplace_latency,
             delete_marketplace_mod,
             increment_download_count,
             update_marketplace_mod,
+            check_game_version,
+            refresh_champion_data,
+            get_champion_data_version,
+            detect_game_locale,
+            check_mod_compatibility,
+            find_duplicate_cache_files,
+            search_cache,
+            verify_cache_integrity,
+            list_cached_champions,
+            list_installed_mods,
+            dedupe_cache_files,
+            set_auto_cache_cleanup,
+            find_orphaned_archives,
+            clean_orphaned_archives,
+            get_import_concurrency,
+            set_import_concurrency,
+            get_mkoverlay_retries,
+            set_mkoverlay_retries,
+            get_overlay_retry_config,
+            set_overlay_retry_config,
+            get_overlay_verify_delay,
+            set_overlay_verify_delay,
+            set_strict_size_verification,
+            get_extraction_filters,
+            set_extraction_filters,
+            export_loadout_file,
+            import_loadout_file,
+            list_log_files,
+            get_log_path,
+            open_log_folder,
+            clear_logs,
+            tail_log,
+            analyze_mod_contents,
+            is_valid_archive,
+            validate_mod_archive,
+            read_mod_info,
+            summarize_activation,
+            verify_mod_champion,
+            preview_game_writes,
+            get_max_concurrent_downloads,
+            set_max_concurrent_downloads,
+            get_file_hash,
+            export_config,
+            import_config,
+            get_integration_status,
 
         ])
         .setup(|app| {
@@ -367,6 +569,32 @@ fn main() {
             println!("[SYSTEM-INFO] Tray: Conditional");
             println!("[SYSTEM-INFO] Discord RPC: Integrated");
 
+            // [OVERLAY-WATCHDOG] Background overlay events need an app handle
+            mod_manager::init_app_handle(app.handle().clone());
+
+            // [DISCORD-RPC] Reconnect if the user left Rich Presence enabled
+            discord_rpc::restore_rpc_state();
+
+            // [OVERLAY-STATUS] Clear phantom "running" state left by a crash
+            mod_manager::reconcile_overlay_status();
+
+            // [TEMP-CLEANUP] No activation runs yet, so leftover temp_ imports are safe to remove
+            std::thread::spawn(|| {
+                let cleaned = mod_manager::remove_temp_folders();
+                if cleaned > 0 {
+                    println!("[SYSTEM-INFO] Removed {} leftover temp import folders", cleaned);
+                }
+            });
+
+            // [CACHE-CLEANUP] Resume scheduled cache cleanup if configured
+            mod_cache::start_auto_cache_cleanup(app.handle().clone());
+
+            // [INTEGRATIONS] Warn early instead of failing later with opaque API errors
+            let integrations = integration_status();
+            if !integrations.unconfigured.is_empty() {
+                println!("[SYSTEM-WARN] Unconfigured integrations: {}", integrations.unconfigured.join(", "));
+            }
+
             // [TRAY-MENU] Create context menu items
             let show_item = MenuItem::with_id(app, "show", "Show Window", true, None::<&str>)?;
             let quit_item = MenuItem::with_id(app, "quit", "Exit", true, None::<&str>)?;
@@ -390,6 +618,7 @@ fn main() {
                     }
                     "quit" => {
                         println!("[TRAY-ACTION] Application exit requested");
+                        mod_manager::shutdown_on_exit();
                         app.exit(0);
                     }
                     _ => {}
@@ -429,6 +658,11 @@ fn main() {
                     // Allow normal close behavior
                 }
             }
+
+            // [SHUTDOWN] Main window gone - do not leave mod-tools.exe running
+            if let WindowEvent::Destroyed = event {
+                mod_manager::shutdown_on_exit();
+            }
         })
         .run(tauri::generate_context!())
         .expect("[SYSTEM-ERROR] Failed to run application");
@@ -3,13 +3,18 @@
 //! Description: Marketplace backend module for GitHub-based mod distribution
 //!              - Download mods from GitHub repository
 //!              - Catalog fetching via GitHub API
+//!              - Catalog cache with ETag revalidation and auto-refresh
 //!              - Local cache management
 //! Language: Rust
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
 use reqwest::Client;
+use tauri::{AppHandle, Emitter};
 use tokio::fs;
+use crate::download_limiter::acquire_download_permit;
 
 // [CONST] GitHub Personal Access Token
 // IMPORTANT: Replace with your own GitHub PAT
@@ -22,6 +27,122 @@ pub fn get_token() -> String {
     GITHUB_TOKEN.to_string()
 }
 
+// [FUNC] User-Agent for GitHub requests (public for other modules) - configurable for forks
+pub fn get_user_agent() -> String {
+    crate::settings::load_settings().user_agent
+}
+
+// [COMMAND] Set the User-Agent sent with marketplace GitHub requests
+#[tauri::command]
+pub async fn set_user_agent(ua: String) -> Result<String, String> {
+    let ua = ua.trim().to_string();
+    if ua.is_empty() {
+        return Err("User-Agent cannot be empty".to_string());
+    }
+    if reqwest::header::HeaderValue::from_str(&ua).is_err() {
+        return Err("User-Agent contains invalid characters".to_string());
+    }
+    
+    crate::settings::update_settings(|s| s.user_agent = ua.clone())?;
+    println!("[MARKETPLACE] User-Agent set to: {}", ua);
+    Ok(ua)
+}
+
+// [FUNC] False while the token is still the shipped placeholder
+pub fn token_configured() -> bool {
+    !GITHUB_TOKEN.trim().is_empty() && !GITHUB_TOKEN.starts_with("YOUR_")
+}
+
+// [CONST] Recent GitHub write operations kept for latency stats
+const LATENCY_SAMPLE_LIMIT: usize = 100;
+
+// [STRUCT] Timing of one GitHub write operation
+struct LatencySample {
+    operation: String,
+    api_ms: u64,
+    success: bool,
+}
+
+// [STATE] Ring buffer of recent operation timings
+static API_LATENCY: Mutex<std::collections::VecDeque<LatencySample>> = Mutex::new(std::collections::VecDeque::new());
+
+// [FUNC] Record time spent in GitHub calls for an operation (upload, like, download_count)
+pub fn record_api_latency(operation: &str, api_ms: u64, success: bool) {
+    if let Ok(mut samples) = API_LATENCY.lock() {
+        if samples.len() >= LATENCY_SAMPLE_LIMIT {
+            samples.pop_front();
+        }
+        samples.push_back(LatencySample { operation: operation.to_string(), api_ms, success });
+    }
+    println!("[MARKETPLACE-LATENCY] {}: {}ms ({})", operation, api_ms, if success { "ok" } else { "failed" });
+}
+
+// [STRUCT] Aggregated timings for one operation type
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationLatency {
+    pub operation: String,
+    pub count: usize,
+    pub failures: usize,
+    pub avg_ms: u64,
+    pub max_ms: u64,
+    pub last_ms: u64,
+}
+
+// [STRUCT] Recent GitHub latency per operation
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LatencyStats {
+    pub samples: usize,
+    pub operations: Vec<OperationLatency>,
+}
+
+// [COMMAND] Aggregate recent GitHub operation timings - tells slow GitHub apart from slow app
+#[tauri::command]
+pub async fn get_marketplace_latency() -> LatencyStats {
+    let samples = match API_LATENCY.lock() {
+        Ok(samples) => samples,
+        Err(_) => return LatencyStats { samples: 0, operations: Vec::new() },
+    };
+    
+    let mut operations: Vec<OperationLatency> = Vec::new();
+    for sample in samples.iter() {
+        let stats = match operations.iter_mut().position(|o| o.operation == sample.operation) {
+            Some(index) => &mut operations[index],
+            None => {
+                operations.push(OperationLatency {
+                    operation: sample.operation.clone(),
+                    count: 0,
+                    failures: 0,
+                    avg_ms: 0,
+                    max_ms: 0,
+                    last_ms: 0,
+                });
+                operations.last_mut().unwrap()
+            }
+        };
+        
+        // avg_ms holds the running total until the final division below
+        stats.count += 1;
+        stats.avg_ms += sample.api_ms;
+        stats.max_ms = stats.max_ms.max(sample.api_ms);
+        stats.last_ms = sample.api_ms;
+        if !sample.success {
+            stats.failures += 1;
+        }
+    }
+    
+    for stats in operations.iter_mut() {
+        stats.avg_ms /= stats.count as u64;
+    }
+    operations.sort_by(|a, b| a.operation.cmp(&b.operation));
+    
+    LatencyStats {
+        samples: samples.len(),
+        operations,
+    }
+}
+
 // [STRUCT] Download result
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -39,24 +160,230 @@ pub struct CatalogFetchResult {
     pub error: Option<String>,
 }
 
+// [STRUCT] Cached catalog with ETag for conditional requests
+#[derive(Serialize, Deserialize, Clone)]
+pub struct CachedCatalog {
+    pub catalog_url: String,
+    pub etag: Option<String>,
+    pub data: String,
+    pub fetched_at: u64,
+}
+
+// [STRUCT] Catalog updated event payload
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CatalogUpdatedEvent {
+    pub data: String,
+    pub fetched_at: u64,
+}
+
+// [STRUCT] Single mod record from the catalog index.json
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ModDetail {
+    pub id: String,
+    pub name: String,
+    pub author: String,
+    pub author_id: String,
+    pub author_avatar: Option<String>,
+    pub description: String,
+    pub title: String,
+    pub tags: Vec<String>,
+    pub version: String,
+    pub download_url: String,
+    pub preview_url: Option<String>,
+    pub file_size: u64,
+    // SHA-256 of mod.fantome - only published for uploads made after hashes were added
+    pub sha256: Option<String>,
+    pub download_count: u64,
+    pub like_count: u64,
+    pub liked_by: Vec<serde_json::Value>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+// [STRUCT] Minimal catalog entry for cheap change detection
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CatalogModId {
+    pub id: String,
+    pub updated_at: String,
+}
+
+// [STRUCT] Headline numbers for a marketplace landing screen
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarketplaceSummary {
+    pub total_mods: u64,
+    pub last_updated: Option<String>,
+    pub total_size: u64,
+}
+
+// [STRUCT] Only the index.json fields needed for the summary - skips full mod records
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+struct IndexSummaryFields {
+    total_mods: Option<u64>,
+    last_updated: Option<String>,
+    mods: Vec<ModSizeField>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+struct ModSizeField {
+    file_size: u64,
+}
+
+// [STRUCT] User who liked a mod - mirrors a likedBy entry in index.json
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ModLiker {
+    pub discord_id: String,
+    pub username: String,
+    pub display_name: String,
+    pub avatar: Option<String>,
+    pub liked_at: String,
+}
+
+// [STRUCT] Most recent commit touching a mod folder
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeInfo {
+    pub sha: String,
+    pub message: String,
+    pub author: String,
+    pub date: String,
+    pub files: Vec<ChangedFile>,
+}
+
+// [STRUCT] File changed by that commit
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct ChangedFile {
+    pub filename: String,
+    pub status: String,
+    pub additions: u64,
+    pub deletions: u64,
+}
+
+// [STRUCT] Only the GitHub commit fields needed for ChangeInfo
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct GitHubCommit {
+    sha: String,
+    commit: GitHubCommitDetail,
+    files: Vec<ChangedFile>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct GitHubCommitDetail {
+    message: String,
+    author: GitHubCommitAuthor,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct GitHubCommitAuthor {
+    name: String,
+    date: String,
+}
+
+// [CONST] Error code when a download does not match the catalog fileSize
+pub const SIZE_MISMATCH: &str = "SIZE_MISMATCH";
+
+// [CONST] Minimum auto-refresh interval to stay within GitHub rate limits
+const MIN_AUTO_REFRESH_SECS: u64 = 30;
+
+// [STATE] In-memory catalog cache (mirrored to disk)
+static CATALOG_CACHE: Mutex<Option<CachedCatalog>> = Mutex::new(None);
+
+// [STATE] Auto-refresh generation - bumping it stops older refresh tasks
+static AUTO_REFRESH_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+// [TYPE] Async index.json locks keyed by (owner, repo)
+type IndexMutexMap = std::collections::HashMap<(String, String), std::sync::Arc<tokio::sync::Mutex<()>>>;
+
+// [STATE] Per-repository async locks serializing index.json read-modify-write commits
+static INDEX_MUTEXES: std::sync::OnceLock<Mutex<IndexMutexMap>> = std::sync::OnceLock::new();
+
+// [FUNC] Get the index.json lock for a repository - operations on other repos never contend
+pub fn get_index_mutex(owner: &str, repo: &str) -> std::sync::Arc<tokio::sync::Mutex<()>> {
+    let map = INDEX_MUTEXES.get_or_init(|| Mutex::new(std::collections::HashMap::new()));
+    let mut guard = map.lock().unwrap_or_else(|e| e.into_inner());
+    guard
+        .entry((owner.to_lowercase(), repo.to_lowercase()))
+        .or_default()
+        .clone()
+}
+
 // [FUNC] Get marketplace cache directory
-fn get_marketplace_cache_dir() -> PathBuf {
+pub fn get_marketplace_cache_dir() -> PathBuf {
     let app_data = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
     app_data.join("Wildflover").join("marketplace")
 }
 
-// [COMMAND] Fetch marketplace catalog via GitHub Contents API
-#[tauri::command]
-pub async fn fetch_marketplace_catalog(catalog_url: String) -> CatalogFetchResult {
+// [FUNC] Get catalog cache file location
+fn get_catalog_cache_file() -> PathBuf {
+    get_marketplace_cache_dir().join("catalog.json")
+}
+
+// [FUNC] Get current unix timestamp
+fn get_unix_timestamp() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+// [FUNC] Get cached catalog - memory first, then disk
+pub fn get_cached_catalog() -> Option<CachedCatalog> {
+    if let Ok(guard) = CATALOG_CACHE.lock() {
+        if guard.is_some() {
+            return guard.clone();
+        }
+    }
+    
+    let text = std::fs::read_to_string(get_catalog_cache_file()).ok()?;
+    let cached: CachedCatalog = serde_json::from_str(&text).ok()?;
+    
+    if let Ok(mut guard) = CATALOG_CACHE.lock() {
+        *guard = Some(cached.clone());
+    }
+    Some(cached)
+}
+
+// [FUNC] Store catalog in memory and on disk
+fn store_cached_catalog(cached: &CachedCatalog) {
+    if let Ok(mut guard) = CATALOG_CACHE.lock() {
+        *guard = Some(cached.clone());
+    }
+    
+    let cache_file = get_catalog_cache_file();
+    if let Some(parent) = cache_file.parent() {
+        std::fs::create_dir_all(parent).ok();
+    }
+    if let Ok(text) = serde_json::to_string(cached) {
+        if let Err(e) = std::fs::write(&cache_file, text) {
+            println!("[MARKETPLACE-CATALOG] WARN: Failed to persist catalog cache: {}", e);
+        }
+    }
+}
+
+// [FUNC] Fetch catalog with ETag revalidation
+// Returns catalog text and whether it changed since the last cached copy
+async fn fetch_catalog_cached(catalog_url: &str) -> Result<(String, bool), String> {
+    fetch_catalog(catalog_url, true).await
+}
+
+// [FUNC] Fetch catalog, optionally replacing the shared cache slot
+// With replace_cache unset a different repo's cached catalog is left alone - same-URL copies are still refreshed
+async fn fetch_catalog(catalog_url: &str, replace_cache: bool) -> Result<(String, bool), String> {
     let parts: Vec<&str> = catalog_url.split('/').collect();
     let (owner, repo) = if parts.len() >= 5 && parts[2] == "raw.githubusercontent.com" {
         (parts[3], parts[4])
     } else {
-        return CatalogFetchResult {
-            success: false,
-            data: None,
-            error: Some("Invalid catalog URL format".to_string()),
-        };
+        return Err("Invalid catalog URL format".to_string());
     };
     
     let api_url = format!(
@@ -66,6 +393,8 @@ pub async fn fetch_marketplace_catalog(catalog_url: String) -> CatalogFetchResul
     
     println!("[MARKETPLACE-CATALOG] Fetching via GitHub API: {}", api_url);
     
+    let _permit = acquire_download_permit().await;
+    
     let client = Client::builder()
         .timeout(std::time::Duration::from_secs(30))
         .build()
@@ -73,53 +402,258 @@ pub async fn fetch_marketplace_catalog(catalog_url: String) -> CatalogFetchResul
     
     let token = get_token();
     
-    match client
+    // [ETAG] Only reuse the cached copy if it belongs to the same catalog
+    let cached = get_cached_catalog().filter(|c| c.catalog_url == catalog_url);
+    let store = replace_cache || cached.is_some();
+    
+    let mut request = client
         .get(&api_url)
         .header("Authorization", format!("Bearer {}", token))
         .header("Accept", "application/vnd.github.raw+json")
-        .header("User-Agent", "Wildflover-Marketplace")
-        .header("X-GitHub-Api-Version", "2022-11-28")
+        .header("User-Agent", get_user_agent())
+        .header("X-GitHub-Api-Version", "2022-11-28");
+    
+    if let Some(etag) = cached.as_ref().and_then(|c| c.etag.clone()) {
+        request = request.header("If-None-Match", etag);
+    }
+    
+    let response = request
         .send()
         .await
-    {
-        Ok(response) => {
-            if !response.status().is_success() {
-                let status = response.status();
-                return CatalogFetchResult {
-                    success: false,
-                    data: None,
-                    error: Some(format!("GitHub API error: HTTP {}", status)),
-                };
-            }
-            
-            match response.text().await {
-                Ok(text) => {
-                    println!("[MARKETPLACE-CATALOG] Fetched {} bytes", text.len());
-                    CatalogFetchResult {
-                        success: true,
-                        data: Some(text),
-                        error: None,
-                    }
-                }
-                Err(e) => CatalogFetchResult {
-                    success: false,
-                    data: None,
-                    error: Some(format!("Failed to read response: {}", e)),
-                },
+        .map_err(|e| format!("Request failed: {}", e))?;
+    
+    // [NOT-MODIFIED] 304 responses do not count against the rate limit
+    if response.status().as_u16() == 304 {
+        if let Some(mut cached) = cached {
+            println!("[MARKETPLACE-CATALOG] Not modified - using cached catalog");
+            cached.fetched_at = get_unix_timestamp();
+            if store {
+                store_cached_catalog(&cached);
             }
+            return Ok((cached.data, false));
         }
+    }
+    
+    if !response.status().is_success() {
+        return Err(format!("GitHub API error: HTTP {}", response.status()));
+    }
+    
+    let etag = response
+        .headers()
+        .get("etag")
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.to_string());
+    
+    let text = response
+        .text()
+        .await
+        .map_err(|e| format!("Failed to read response: {}", e))?;
+    
+    println!("[MARKETPLACE-CATALOG] Fetched {} bytes", text.len());
+    
+    let changed = cached.as_ref().map(|c| c.data != text).unwrap_or(true);
+    
+    if store {
+        store_cached_catalog(&CachedCatalog {
+            catalog_url: catalog_url.to_string(),
+            etag,
+            data: text.clone(),
+            fetched_at: get_unix_timestamp(),
+        });
+    }
+    
+    Ok((text, changed))
+}
+
+// [COMMAND] Fetch marketplace catalog via GitHub Contents API
+#[tauri::command]
+pub async fn fetch_marketplace_catalog(catalog_url: String) -> CatalogFetchResult {
+    match fetch_catalog_cached(&catalog_url).await {
+        Ok((text, _)) => CatalogFetchResult {
+            success: true,
+            data: Some(text),
+            error: None,
+        },
         Err(e) => CatalogFetchResult {
             success: false,
             data: None,
-            error: Some(format!("Request failed: {}", e)),
+            error: Some(e),
         },
     }
 }
 
+// [COMMAND] Enable/disable periodic catalog refresh - None disables
+// Uses the catalog URL from the last successful fetch
+#[tauri::command]
+pub async fn set_catalog_auto_refresh(app: AppHandle, interval_secs: Option<u64>) -> Result<bool, String> {
+    // Bump generation so any running refresh task exits on its next tick
+    let generation = AUTO_REFRESH_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    
+    let interval = match interval_secs {
+        Some(secs) if secs > 0 => secs.max(MIN_AUTO_REFRESH_SECS),
+        _ => {
+            println!("[MARKETPLACE-CATALOG] Auto-refresh disabled");
+            return Ok(false);
+        }
+    };
+    
+    let catalog_url = match get_cached_catalog() {
+        Some(cached) => cached.catalog_url,
+        None => return Err("Catalog must be fetched once before enabling auto-refresh".to_string()),
+    };
+    
+    println!("[MARKETPLACE-CATALOG] Auto-refresh enabled every {}s", interval);
+    
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(std::time::Duration::from_secs(interval)).await;
+            
+            if AUTO_REFRESH_GENERATION.load(Ordering::SeqCst) != generation || crate::mod_manager::is_shutting_down() {
+                println!("[MARKETPLACE-CATALOG] Auto-refresh task stopped");
+                break;
+            }
+            
+            // [TTL] Skip if catalog was refreshed recently (e.g. manual refresh)
+            let fresh = get_cached_catalog()
+                .map(|c| get_unix_timestamp().saturating_sub(c.fetched_at) < interval)
+                .unwrap_or(false);
+            if fresh {
+                continue;
+            }
+            
+            match fetch_catalog_cached(&catalog_url).await {
+                Ok((data, true)) => {
+                    println!("[MARKETPLACE-CATALOG] Catalog changed - emitting catalog-updated");
+                    let _ = app.emit("catalog-updated", CatalogUpdatedEvent {
+                        data,
+                        fetched_at: get_unix_timestamp(),
+                    });
+                }
+                Ok((_, false)) => {}
+                Err(e) => println!("[MARKETPLACE-CATALOG] Auto-refresh failed: {}", e),
+            }
+        }
+    });
+    
+    Ok(true)
+}
+
+
+// [STRUCT] Download progress event payload
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadProgressEvent {
+    pub mod_id: String,
+    pub downloaded: u64,
+    pub total: Option<u64>,
+    pub bytes_per_sec: Option<u64>,
+    pub eta_secs: Option<u64>,
+}
+
+// [CONST] Progress event pacing and rate smoothing window
+const PROGRESS_EMIT_INTERVAL_MS: u128 = 250;
+const RATE_WINDOW_SECS: f64 = 3.0;
+
+// [STRUCT] Moving-average download rate over the last few seconds - keeps the ETA from jittering
+struct RateTracker {
+    samples: std::collections::VecDeque<(std::time::Instant, u64)>,
+}
+
+impl RateTracker {
+    fn new() -> Self {
+        let mut samples = std::collections::VecDeque::new();
+        samples.push_back((std::time::Instant::now(), 0));
+        Self { samples }
+    }
+    
+    // Record cumulative bytes and return the windowed rate in bytes/sec
+    fn record(&mut self, downloaded: u64) -> Option<u64> {
+        let now = std::time::Instant::now();
+        self.samples.push_back((now, downloaded));
+        
+        while self.samples.len() > 2
+            && now.duration_since(self.samples[0].0).as_secs_f64() > RATE_WINDOW_SECS
+        {
+            self.samples.pop_front();
+        }
+        
+        let (start, start_bytes) = self.samples[0];
+        let elapsed = now.duration_since(start).as_secs_f64();
+        if elapsed <= 0.0 {
+            return None;
+        }
+        
+        Some(((downloaded - start_bytes) as f64 / elapsed) as u64)
+    }
+}
+
+// [FUNC] Stream a response body to disk, emitting download-progress with rate and ETA
+async fn stream_to_file(
+    app: &AppHandle,
+    mod_id: &str,
+    mut response: reqwest::Response,
+    path: &std::path::Path,
+    total: Option<u64>,
+) -> Result<u64, String> {
+    use tokio::io::AsyncWriteExt;
+    
+    let mut file = fs::File::create(path)
+        .await
+        .map_err(|e| format!("Failed to write file: {}", e))?;
+    
+    let mut downloaded: u64 = 0;
+    let mut rate = RateTracker::new();
+    let mut last_emit = std::time::Instant::now();
+    
+    loop {
+        let chunk = response
+            .chunk()
+            .await
+            .map_err(|e| format!("Failed to read response: {}", e))?;
+        
+        let finished = chunk.is_none();
+        if let Some(chunk) = chunk {
+            file.write_all(&chunk)
+                .await
+                .map_err(|e| format!("Failed to write file: {}", e))?;
+            downloaded += chunk.len() as u64;
+        }
+        
+        // Throttle events - the final one always goes out so the UI reaches 100%
+        if finished || last_emit.elapsed().as_millis() >= PROGRESS_EMIT_INTERVAL_MS {
+            let bytes_per_sec = rate.record(downloaded).filter(|r| *r > 0);
+            let eta_secs = match (total, bytes_per_sec) {
+                (Some(total), Some(r)) => Some(total.saturating_sub(downloaded) / r),
+                _ => None,
+            };
+            
+            let _ = app.emit("download-progress", DownloadProgressEvent {
+                mod_id: mod_id.to_string(),
+                downloaded,
+                total,
+                bytes_per_sec,
+                eta_secs,
+            });
+            last_emit = std::time::Instant::now();
+        }
+        
+        if finished {
+            break;
+        }
+    }
+    
+    file.flush()
+        .await
+        .map_err(|e| format!("Failed to write file: {}", e))?;
+    
+    Ok(downloaded)
+}
 
 // [COMMAND] Download mod from marketplace via GitHub API
+// Streams to disk and emits download-progress events (bytes, rate, ETA)
 #[tauri::command]
 pub async fn download_marketplace_mod(
+    app: AppHandle,
     mod_id: String,
     download_url: String,
     mod_name: String,
@@ -175,6 +709,9 @@ pub async fn download_marketplace_mod(
     
     println!("[MARKETPLACE-DOWNLOAD] Using API URL: {}", api_url);
     
+    // [THROTTLE] Share the global download budget with skin/preview fetches
+    let _permit = acquire_download_permit().await;
+    
     let github_token = get_token();
     let client = Client::builder()
         .timeout(std::time::Duration::from_secs(300))
@@ -185,7 +722,7 @@ pub async fn download_marketplace_mod(
         .get(&api_url)
         .header("Authorization", format!("Bearer {}", github_token))
         .header("Accept", "application/vnd.github.raw+json")
-        .header("User-Agent", "Wildflover-Marketplace")
+        .header("User-Agent", get_user_agent())
         .header("X-GitHub-Api-Version", "2022-11-28")
         .send()
         .await
@@ -203,39 +740,60 @@ pub async fn download_marketplace_mod(
                 };
             }
             
-            match response.bytes().await {
-                Ok(bytes) => {
-                    println!("[MARKETPLACE-DOWNLOAD] Downloaded {} bytes", bytes.len());
-                    
-                    if bytes.len() < 100 {
-                        return DownloadResult {
-                            success: false,
-                            local_path: None,
-                            error: Some("Downloaded file too small".to_string()),
-                        };
-                    }
-                    
-                    if let Err(e) = fs::write(&mod_file, &bytes).await {
-                        return DownloadResult {
-                            success: false,
-                            local_path: None,
-                            error: Some(format!("Failed to write file: {}", e)),
-                        };
-                    }
-                    
-                    println!("[MARKETPLACE-DOWNLOAD] Saved to: {:?}", mod_file);
-                    
-                    DownloadResult {
-                        success: true,
-                        local_path: Some(mod_file.to_string_lossy().to_string()),
-                        error: None,
-                    }
+            let total = response.content_length();
+            
+            let downloaded = match stream_to_file(&app, &mod_id, response, &mod_file, total).await {
+                Ok(downloaded) => downloaded,
+                Err(e) => {
+                    let _ = std::fs::remove_dir_all(&mod_dir);
+                    return DownloadResult {
+                        success: false,
+                        local_path: None,
+                        error: Some(e),
+                    };
                 }
-                Err(e) => DownloadResult {
+            };
+            
+            println!("[MARKETPLACE-DOWNLOAD] Downloaded {} bytes", downloaded);
+            
+            if downloaded < 100 {
+                let _ = std::fs::remove_dir_all(&mod_dir);
+                return DownloadResult {
                     success: false,
                     local_path: None,
-                    error: Some(format!("Failed to read response: {}", e)),
-                },
+                    error: Some("Downloaded file too small".to_string()),
+                };
+            }
+            
+            println!("[MARKETPLACE-DOWNLOAD] Saved to: {:?}", mod_file);
+            
+            // [SIZE-CHECK] Truncated or swapped files rarely keep the catalog size
+            if let Some(expected) = expected_catalog_size(&mod_id) {
+                if expected != downloaded {
+                    println!("[MARKETPLACE-DOWNLOAD] Size mismatch: expected {}, got {}", expected, downloaded);
+                    let _ = std::fs::remove_dir_all(&mod_dir);
+                    return DownloadResult {
+                        success: false,
+                        local_path: None,
+                        error: Some(SIZE_MISMATCH.to_string()),
+                    };
+                }
+            }
+            
+            // [PRE-CHECK] Reject corrupt archives instead of failing later during import
+            if !crate::mod_archive::archive_is_valid(&mod_file) {
+                let _ = std::fs::remove_dir_all(&mod_dir);
+                return DownloadResult {
+                    success: false,
+                    local_path: None,
+                    error: Some(crate::mod_archive::CORRUPT_ARCHIVE.to_string()),
+                };
+            }
+            
+            DownloadResult {
+                success: true,
+                local_path: Some(mod_file.to_string_lossy().to_string()),
+                error: None,
             }
         }
         Err(e) => DownloadResult {
@@ -281,6 +839,40 @@ pub async fn delete_marketplace_mod_cache(mod_id: String) -> bool {
     false
 }
 
+// [ENUM] Local state of a marketplace mod
+#[derive(Serialize)]
+pub enum ModInstallStatus {
+    NotDownloaded,
+    Downloaded,
+    Installed,
+}
+
+// [COMMAND] Check whether a marketplace mod is downloaded and/or imported locally
+// Installed means mod-tools already built it into overlay/installed
+#[tauri::command]
+pub async fn is_marketplace_mod_installed(mod_id: String) -> ModInstallStatus {
+    let installed_dir = crate::mod_manager::get_overlay_directory()
+        .join("installed")
+        .join(format!("marketplace_{}", mod_id));
+    let cached_file = get_marketplace_cache_dir().join(&mod_id).join("mod.fantome");
+    
+    let status = if installed_dir.is_dir() {
+        ModInstallStatus::Installed
+    } else if cached_file.is_file() {
+        ModInstallStatus::Downloaded
+    } else {
+        ModInstallStatus::NotDownloaded
+    };
+    
+    println!("[MARKETPLACE-CACHE] Install status {}: {}", mod_id, match status {
+        ModInstallStatus::Installed => "installed",
+        ModInstallStatus::Downloaded => "downloaded",
+        ModInstallStatus::NotDownloaded => "not downloaded",
+    });
+    
+    status
+}
+
 // [STRUCT] Preview fetch result
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -290,19 +882,347 @@ pub struct PreviewFetchResult {
     pub error: Option<String>,
 }
 
+// [CONST] Max concurrent preview requests for batch fetch
+const PREVIEW_BATCH_CONCURRENCY: usize = 4;
+
+// [CONST] JPEG quality for downscaled previews
+const PREVIEW_JPEG_QUALITY: u8 = 85;
+
+// [CONST] Preview file names tried in order - uploads use JPEG, the update flow may store PNG/WebP
+const PREVIEW_FILE_NAMES: [&str; 3] = ["preview.jpg", "preview.png", "preview.webp"];
+
+// [FUNC] Get preview data URL cache directory
+fn get_preview_cache_dir() -> PathBuf {
+    let app_data = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
+    app_data.join("Wildflover").join("previews")
+}
+
+// [FUNC] Mod ids become file names - only [A-Za-z0-9_-] is allowed so they cannot escape the cache dir
+fn is_valid_mod_id(mod_id: &str) -> bool {
+    !mod_id.is_empty() && mod_id.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+// [FUNC] Preview cache file name - downscaled copies are keyed by their max dimension
+// Returns None for ids that are not safe to use as a file name
+fn preview_cache_name(mod_id: &str, max_dimension: Option<u32>) -> Option<String> {
+    if !is_valid_mod_id(mod_id) {
+        return None;
+    }
+    Some(match max_dimension {
+        Some(max) => format!("{}@{}.txt", mod_id, max),
+        None => format!("{}.txt", mod_id),
+    })
+}
+
+// [FUNC] Read cached preview data URL for a mod
+fn read_cached_preview(mod_id: &str, max_dimension: Option<u32>) -> Option<String> {
+    let cache_file = get_preview_cache_dir().join(preview_cache_name(mod_id, max_dimension)?);
+    std::fs::read_to_string(cache_file).ok().filter(|s| s.starts_with("data:"))
+}
+
+// [FUNC] Store preview data URL in disk cache
+fn write_cached_preview(mod_id: &str, max_dimension: Option<u32>, data_url: &str) {
+    let Some(name) = preview_cache_name(mod_id, max_dimension) else {
+        return;
+    };
+    let cache_dir = get_preview_cache_dir();
+    if std::fs::create_dir_all(&cache_dir).is_ok() {
+        let _ = std::fs::write(cache_dir.join(name), data_url);
+    }
+}
+
+// [FUNC] Remove cached preview and its downscaled copies - called when a mod's preview is replaced
+pub fn invalidate_cached_preview(mod_id: &str) {
+    let Some(name) = preview_cache_name(mod_id, None) else {
+        return;
+    };
+    let cache_dir = get_preview_cache_dir();
+    let cache_file = cache_dir.join(name);
+    if cache_file.exists() {
+        let _ = std::fs::remove_file(cache_file);
+    }
+    
+    let scaled_prefix = format!("{}@", mod_id);
+    if let Ok(entries) = std::fs::read_dir(&cache_dir) {
+        for entry in entries.filter_map(|e| e.ok()) {
+            if entry.file_name().to_string_lossy().starts_with(&scaled_prefix) {
+                let _ = std::fs::remove_file(entry.path());
+            }
+        }
+    }
+}
+
+// [FUNC] Downscale preview to fit max_dimension - None if already small enough or undecodable
+fn downscale_preview(bytes: &[u8], max_dimension: u32) -> Option<Vec<u8>> {
+    let img = match image::load_from_memory(bytes) {
+        Ok(img) => img,
+        Err(e) => {
+            println!("[MARKETPLACE-PREVIEW] WARN: Cannot decode preview for downscale: {}", e);
+            return None;
+        }
+    };
+    
+    if img.width() <= max_dimension && img.height() <= max_dimension {
+        return None;
+    }
+    
+    // resize keeps the aspect ratio and fits inside the box
+    let scaled = img
+        .resize(max_dimension, max_dimension, image::imageops::FilterType::Triangle)
+        .to_rgb8();
+    
+    let mut output = Vec::new();
+    let encoder = image::codecs::jpeg::JpegEncoder::new_with_quality(&mut output, PREVIEW_JPEG_QUALITY);
+    if let Err(e) = scaled.write_with_encoder(encoder) {
+        println!("[MARKETPLACE-PREVIEW] WARN: Failed to encode downscaled preview: {}", e);
+        return None;
+    }
+    
+    println!("[MARKETPLACE-PREVIEW] Downscaled {}x{} -> {}x{} ({} -> {} bytes)",
+             img.width(), img.height(), scaled.width(), scaled.height(), bytes.len(), output.len());
+    
+    Some(output)
+}
+
+// [FUNC] Image MIME type from magic bytes - falls back to the file extension
+fn preview_mime(bytes: &[u8], file_name: &str) -> &'static str {
+    if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        "image/jpeg"
+    } else if bytes.starts_with(&[0x89, b'P', b'N', b'G']) {
+        "image/png"
+    } else if bytes.len() >= 12 && &bytes[0..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+        "image/webp"
+    } else if file_name.ends_with(".png") {
+        "image/png"
+    } else if file_name.ends_with(".webp") {
+        "image/webp"
+    } else {
+        "image/jpeg"
+    }
+}
+
+// [FUNC] Fetch preview image and encode as data URL
+// Tries each known preview file name - Ok(None) when the mod has no preview at all
+async fn fetch_preview_data_url(
+    client: &Client,
+    mod_id: &str,
+    github_owner: &str,
+    github_repo: &str,
+    max_dimension: Option<u32>,
+) -> Result<Option<String>, String> {
+    let _permit = acquire_download_permit().await;
+    let token = get_token();
+    
+    let mut found: Option<(&str, Vec<u8>)> = None;
+    
+    for file_name in PREVIEW_FILE_NAMES {
+        let api_url = format!(
+            "https://api.github.com/repos/{}/{}/contents/mods/{}/{}",
+            github_owner, github_repo, mod_id, file_name
+        );
+        
+        let response = client
+            .get(&api_url)
+            .header("Authorization", format!("Bearer {}", token))
+            .header("Accept", "application/vnd.github.raw+json")
+            .header("User-Agent", get_user_agent())
+            .header("X-GitHub-Api-Version", "2022-11-28")
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+        
+        // [FALLBACK] Missing file - try the next format
+        if response.status().as_u16() == 404 {
+            continue;
+        }
+        
+        if !response.status().is_success() {
+            return Err(format!("HTTP {}", response.status()));
+        }
+        
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(|e| format!("Failed to read response: {}", e))?;
+        
+        found = Some((file_name, bytes.to_vec()));
+        break;
+    }
+    
+    let (file_name, bytes) = match found {
+        Some(found) => found,
+        None => {
+            println!("[MARKETPLACE-PREVIEW] No preview found for {}", mod_id);
+            return Ok(None);
+        }
+    };
+    
+    // [DOWNSCALE] Oversized legacy uploads are shrunk before reaching the gallery
+    let bytes = match max_dimension {
+        Some(max) if max > 0 => {
+            tokio::task::spawn_blocking(move || downscale_preview(&bytes, max).unwrap_or(bytes))
+                .await
+                .map_err(|e| format!("Downscale task failed: {}", e))?
+        }
+        _ => bytes,
+    };
+    
+    // Downscaled copies are re-encoded as JPEG - sniff the final bytes rather than trusting the name
+    let mime = preview_mime(&bytes, file_name);
+    
+    // Convert to base64 data URL
+    use base64::Engine;
+    let base64_str = base64::engine::general_purpose::STANDARD.encode(&bytes);
+    
+    println!("[MARKETPLACE-PREVIEW] Fetched {} bytes for {} ({}, {})", bytes.len(), mod_id, file_name, mime);
+    
+    Ok(Some(format!("data:{};base64,{}", mime, base64_str)))
+}
+
 // [COMMAND] Fetch mod preview image via GitHub API (bypasses CDN cache)
 #[tauri::command]
 pub async fn fetch_mod_preview(
     mod_id: String,
     github_owner: String,
     github_repo: String,
+    max_dimension: Option<u32>,
 ) -> PreviewFetchResult {
+    println!("[MARKETPLACE-PREVIEW] Fetching: {} (max dimension: {:?})", mod_id, max_dimension);
+    
+    if !is_valid_mod_id(&mod_id) {
+        return PreviewFetchResult {
+            success: false,
+            data_url: None,
+            error: Some(format!("Invalid mod id: {}", mod_id)),
+        };
+    }
+    
+    let max_dimension = max_dimension.filter(|max| *max > 0);
+    
+    // [CACHE] Downscaled copies are reused - the full-size preview is always refetched
+    if max_dimension.is_some() {
+        if let Some(cached) = read_cached_preview(&mod_id, max_dimension) {
+            return PreviewFetchResult {
+                success: true,
+                data_url: Some(cached),
+                error: None,
+            };
+        }
+    }
+    
+    let client = Client::builder()
+        .timeout(std::time::Duration::from_secs(30))
+        .build()
+        .unwrap_or_else(|_| Client::new());
+    
+    match fetch_preview_data_url(&client, &mod_id, &github_owner, &github_repo, max_dimension).await {
+        Ok(None) => PreviewFetchResult {
+            success: true,
+            data_url: None,
+            error: None,
+        },
+        Ok(Some(data_url)) => {
+            // Refresh batch cache with the latest copy
+            write_cached_preview(&mod_id, max_dimension, &data_url);
+            PreviewFetchResult {
+                success: true,
+                data_url: Some(data_url),
+                error: None,
+            }
+        }
+        Err(e) => PreviewFetchResult {
+            success: false,
+            data_url: None,
+            error: Some(e),
+        },
+    }
+}
+
+// [COMMAND] Fetch many previews at once - disk cache first, bounded concurrency for misses
+// Returns (mod_id, data_url) pairs in input order
+#[tauri::command]
+pub async fn fetch_previews_batch(
+    mod_ids: Vec<String>,
+    github_owner: String,
+    github_repo: String,
+) -> Vec<(String, Option<String>)> {
+    println!("[MARKETPLACE-PREVIEW] Batch fetch for {} mods", mod_ids.len());
+    
+    let client = Client::builder()
+        .timeout(std::time::Duration::from_secs(30))
+        .build()
+        .unwrap_or_else(|_| Client::new());
+    
+    let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(PREVIEW_BATCH_CONCURRENCY));
+    let mut results: Vec<(String, Option<String>)> = Vec::with_capacity(mod_ids.len());
+    let mut tasks = tokio::task::JoinSet::new();
+    let mut cache_hits = 0;
+    
+    for (index, mod_id) in mod_ids.iter().enumerate() {
+        if !is_valid_mod_id(mod_id) {
+            println!("[MARKETPLACE-PREVIEW] Skipping invalid mod id: {}", mod_id);
+            results.push((mod_id.clone(), None));
+            continue;
+        }
+        
+        if let Some(cached) = read_cached_preview(mod_id, None) {
+            cache_hits += 1;
+            results.push((mod_id.clone(), Some(cached)));
+            continue;
+        }
+        
+        results.push((mod_id.clone(), None));
+        
+        let client = client.clone();
+        let semaphore = semaphore.clone();
+        let mod_id = mod_id.clone();
+        let owner = github_owner.clone();
+        let repo = github_repo.clone();
+        
+        tasks.spawn(async move {
+            let _permit = semaphore.acquire_owned().await.ok();
+            match fetch_preview_data_url(&client, &mod_id, &owner, &repo, None).await {
+                Ok(None) => (index, None),
+                Ok(Some(data_url)) => {
+                    write_cached_preview(&mod_id, None, &data_url);
+                    (index, Some(data_url))
+                }
+                Err(e) => {
+                    println!("[MARKETPLACE-PREVIEW] Batch fetch failed for {}: {}", mod_id, e);
+                    (index, None)
+                }
+            }
+        });
+    }
+    
+    while let Some(joined) = tasks.join_next().await {
+        if let Ok((index, data_url)) = joined {
+            results[index].1 = data_url;
+        }
+    }
+    
+    println!("[MARKETPLACE-PREVIEW] Batch complete: {} cached, {} fetched", 
+             cache_hits, results.len() - cache_hits);
+    
+    results
+}
+
+// [COMMAND] Fetch raw info.json of a marketplace mod via GitHub API
+// Reads the per-mod metadata directly - useful when it drifts from the aggregated index.json
+#[tauri::command]
+pub async fn fetch_mod_info_json(
+    mod_id: String,
+    github_owner: String,
+    github_repo: String,
+) -> Result<String, String> {
+    println!("[MARKETPLACE-INFO] Fetching info.json: {}", mod_id);
+    
     let api_url = format!(
-        "https://api.github.com/repos/{}/{}/contents/mods/{}/preview.jpg",
+        "https://api.github.com/repos/{}/{}/contents/mods/{}/info.json",
         github_owner, github_repo, mod_id
     );
     
-    println!("[MARKETPLACE-PREVIEW] Fetching: {}", mod_id);
+    let _permit = acquire_download_permit().await;
     
     let client = Client::builder()
         .timeout(std::time::Duration::from_secs(30))
@@ -311,51 +1231,206 @@ pub async fn fetch_mod_preview(
     
     let token = get_token();
     
-    match client
+    let response = client
         .get(&api_url)
         .header("Authorization", format!("Bearer {}", token))
         .header("Accept", "application/vnd.github.raw+json")
-        .header("User-Agent", "Wildflover-Marketplace")
+        .header("User-Agent", get_user_agent())
         .header("X-GitHub-Api-Version", "2022-11-28")
         .send()
         .await
-    {
-        Ok(response) => {
-            if !response.status().is_success() {
-                let status = response.status();
-                return PreviewFetchResult {
-                    success: false,
-                    data_url: None,
-                    error: Some(format!("HTTP {}", status)),
-                };
-            }
-            
-            match response.bytes().await {
-                Ok(bytes) => {
-                    // Convert to base64 data URL
-                    use base64::Engine;
-                    let base64_str = base64::engine::general_purpose::STANDARD.encode(&bytes);
-                    let data_url = format!("data:image/jpeg;base64,{}", base64_str);
-                    
-                    println!("[MARKETPLACE-PREVIEW] Fetched {} bytes for {}", bytes.len(), mod_id);
-                    
-                    PreviewFetchResult {
-                        success: true,
-                        data_url: Some(data_url),
-                        error: None,
-                    }
-                }
-                Err(e) => PreviewFetchResult {
-                    success: false,
-                    data_url: None,
-                    error: Some(format!("Failed to read response: {}", e)),
-                },
-            }
+        .map_err(|e| format!("Request failed: {}", e))?;
+    
+    if !response.status().is_success() {
+        return Err(format!("HTTP {}", response.status()));
+    }
+    
+    let text = response
+        .text()
+        .await
+        .map_err(|e| format!("Failed to read response: {}", e))?;
+    
+    println!("[MARKETPLACE-INFO] Fetched {} bytes for {}", text.len(), mod_id);
+    
+    Ok(text)
+}
+
+// [FUNC] Catalog fileSize a download must match - None skips the check
+// Missing sizes (legacy entries) and a disabled strict setting both skip verification
+pub fn expected_catalog_size(mod_id: &str) -> Option<u64> {
+    if !crate::settings::load_settings().strict_size_verification {
+        return None;
+    }
+    get_cached_mods()
+        .into_iter()
+        .find(|m| m.id == mod_id)
+        .map(|m| m.file_size)
+        .filter(|size| *size > 0)
+}
+
+// [FUNC] GET a GitHub API URL and parse the JSON body
+async fn github_api_get<T: serde::de::DeserializeOwned>(client: &Client, url: &str) -> Result<T, String> {
+    let response = client
+        .get(url)
+        .header("Authorization", format!("Bearer {}", get_token()))
+        .header("Accept", "application/vnd.github+json")
+        .header("User-Agent", get_user_agent())
+        .header("X-GitHub-Api-Version", "2022-11-28")
+        .send()
+        .await
+        .map_err(|e| format!("Request failed: {}", e))?;
+    
+    if !response.status().is_success() {
+        return Err(format!("HTTP {}", response.status()));
+    }
+    
+    response
+        .json::<T>()
+        .await
+        .map_err(|e| format!("Failed to parse response: {}", e))
+}
+
+// [COMMAND] Fetch the latest commit touching mods/{mod_id}/ with its changed files
+// Lets authors confirm an update or file replacement committed what they expected
+// Returns None when no commit has touched the mod folder
+#[tauri::command]
+pub async fn fetch_mod_latest_change(
+    mod_id: String,
+    owner: String,
+    repo: String,
+) -> Result<Option<ChangeInfo>, String> {
+    println!("[MARKETPLACE-CHANGE] Fetching latest change: {}", mod_id);
+    
+    let _permit = acquire_download_permit().await;
+    
+    let client = Client::builder()
+        .timeout(std::time::Duration::from_secs(30))
+        .build()
+        .unwrap_or_else(|_| Client::new());
+    
+    let mod_path = format!("mods/{}/", mod_id);
+    let list_url = format!(
+        "https://api.github.com/repos/{}/{}/commits?path={}&per_page=1",
+        owner, repo, mod_path
+    );
+    
+    let latest = match github_api_get::<Vec<GitHubCommit>>(&client, &list_url).await?.into_iter().next() {
+        Some(commit) => commit,
+        None => {
+            println!("[MARKETPLACE-CHANGE] No commits for {}", mod_id);
+            return Ok(None);
         }
-        Err(e) => PreviewFetchResult {
-            success: false,
-            data_url: None,
-            error: Some(format!("Request failed: {}", e)),
-        },
+    };
+    
+    // The list endpoint omits files - fetch the single commit for them
+    let commit_url = format!("https://api.github.com/repos/{}/{}/commits/{}", owner, repo, latest.sha);
+    let commit: GitHubCommit = github_api_get(&client, &commit_url).await?;
+    
+    let files: Vec<ChangedFile> = commit
+        .files
+        .into_iter()
+        .filter(|f| f.filename.starts_with(&mod_path))
+        .collect();
+    
+    println!("[MARKETPLACE-CHANGE] {} at {}: {} files", mod_id, &commit.sha[..commit.sha.len().min(7)], files.len());
+    
+    Ok(Some(ChangeInfo {
+        sha: commit.sha,
+        message: commit.commit.message,
+        author: commit.commit.author.name,
+        date: commit.commit.author.date,
+        files,
+    }))
+}
+
+// [FUNC] Parse mod records from the cached catalog
+pub fn get_cached_mods() -> Vec<ModDetail> {
+    let cached = match get_cached_catalog() {
+        Some(cached) => cached,
+        None => return Vec::new(),
+    };
+    
+    serde_json::from_str::<serde_json::Value>(&cached.data)
+        .ok()
+        .and_then(|index| index.get("mods").cloned())
+        .and_then(|mods| serde_json::from_value::<Vec<ModDetail>>(mods).ok())
+        .unwrap_or_default()
+}
+
+// [COMMAND] List only mod ids and update times - revalidates via ETag when a catalog URL is given
+#[tauri::command]
+pub async fn fetch_catalog_ids(catalog_url: Option<String>) -> Result<Vec<CatalogModId>, String> {
+    if let Some(url) = catalog_url {
+        fetch_catalog_cached(&url).await?;
     }
+    
+    let ids: Vec<CatalogModId> = get_cached_mods()
+        .into_iter()
+        .map(|m| CatalogModId { id: m.id, updated_at: m.updated_at })
+        .collect();
+    
+    println!("[MARKETPLACE-CATALOG] Catalog ids: {}", ids.len());
+    Ok(ids)
+}
+
+// [COMMAND] Mod count, last update and total file size of a marketplace repo
+// Reuses the ETag-cached catalog when it is the same repo - other repos never replace the shared cache
+#[tauri::command]
+pub async fn get_marketplace_summary(owner: String, repo: String) -> Result<MarketplaceSummary, String> {
+    let catalog_url = format!("https://raw.githubusercontent.com/{}/{}/main/index.json", owner, repo);
+    let (text, _) = fetch_catalog(&catalog_url, false).await?;
+    
+    let index: IndexSummaryFields = serde_json::from_str(&text)
+        .map_err(|e| format!("Failed to parse index.json: {}", e))?;
+    
+    let summary = MarketplaceSummary {
+        // totalMods is maintained by uploads - fall back to counting if it is missing
+        total_mods: index.total_mods.unwrap_or(index.mods.len() as u64),
+        last_updated: index.last_updated,
+        total_size: index.mods.iter().map(|m| m.file_size).sum(),
+    };
+    
+    println!("[MARKETPLACE-SUMMARY] {}/{}: {} mods, {} bytes",
+             owner, repo, summary.total_mods, summary.total_size);
+    
+    Ok(summary)
+}
+
+// [COMMAND] List users who liked a mod from the cached catalog
+// Malformed likedBy entries are skipped - empty list when the mod is unknown
+#[tauri::command]
+pub async fn fetch_mod_likers(mod_id: String) -> Vec<ModLiker> {
+    let likers: Vec<ModLiker> = get_cached_mods()
+        .into_iter()
+        .find(|m| m.id == mod_id)
+        .map(|m| m.liked_by
+            .into_iter()
+            .filter_map(|v| serde_json::from_value::<ModLiker>(v).ok())
+            .collect())
+        .unwrap_or_default();
+    
+    println!("[MARKETPLACE-LIKES] Likers for {}: {}", mod_id, likers.len());
+    likers
+}
+
+// [COMMAND] Get several mods' records from the cached catalog in one call
+// Returns entries in request order - None for ids not in the catalog
+#[tauri::command]
+pub async fn fetch_mods_detail(mod_ids: Vec<String>) -> Vec<Option<ModDetail>> {
+    let mods = get_cached_mods();
+    
+    let by_id: std::collections::HashMap<&str, &ModDetail> = mods
+        .iter()
+        .map(|m| (m.id.as_str(), m))
+        .collect();
+    
+    let details: Vec<Option<ModDetail>> = mod_ids
+        .iter()
+        .map(|id| by_id.get(id.as_str()).map(|m| (*m).clone()))
+        .collect();
+    
+    println!("[MARKETPLACE-DETAIL] Resolved {}/{} mods from cached catalog",
+             details.iter().filter(|d| d.is_some()).count(), mod_ids.len());
+    
+    details
 }
@@ -7,6 +7,7 @@
 //! Language: Rust
 
 use reqwest::Client;
+use crate::marketplace::get_user_agent;
 use serde::Serialize;
 use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
 use crate::marketplace_catalog::{
@@ -48,7 +49,7 @@ pub async fn delete_marketplace_mod(
         .get(format!("{}/git/ref/heads/main", api_base))
         .header("Authorization", format!("Bearer {}", github_token))
         .header("Accept", "application/vnd.github+json")
-        .header("User-Agent", "Wildflover-Marketplace")
+        .header("User-Agent", get_user_agent())
         .send()
         .await
     {
@@ -82,7 +83,7 @@ pub async fn delete_marketplace_mod(
         .get(&index_url)
         .header("Authorization", format!("Bearer {}", github_token))
         .header("Accept", "application/vnd.github.raw+json")
-        .header("User-Agent", "Wildflover-Marketplace")
+        .header("User-Agent", get_user_agent())
         .send()
         .await
     {
@@ -137,7 +138,7 @@ pub async fn delete_marketplace_mod(
         .post(format!("{}/git/blobs", api_base))
         .header("Authorization", format!("Bearer {}", github_token))
         .header("Accept", "application/vnd.github+json")
-        .header("User-Agent", "Wildflover-Marketplace")
+        .header("User-Agent", get_user_agent())
         .json(&serde_json::json!({
             "content": index_base64,
             "encoding": "base64"
@@ -161,7 +162,7 @@ pub async fn delete_marketplace_mod(
         .get(&contents_url)
         .header("Authorization", format!("Bearer {}", github_token))
         .header("Accept", "application/vnd.github+json")
-        .header("User-Agent", "Wildflover-Marketplace")
+        .header("User-Agent", get_user_agent())
         .send()
         .await
     {
@@ -212,7 +213,7 @@ pub async fn delete_marketplace_mod(
         .post(format!("{}/git/trees", api_base))
         .header("Authorization", format!("Bearer {}", github_token))
         .header("Accept", "application/vnd.github+json")
-        .header("User-Agent", "Wildflover-Marketplace")
+        .header("User-Agent", get_user_agent())
         .json(&serde_json::json!({
             "base_tree": base_sha,
             "tree": tree_items
@@ -245,7 +246,7 @@ pub async fn delete_marketplace_mod(
         .post(format!("{}/git/commits", api_base))
         .header("Authorization", format!("Bearer {}", github_token))
         .header("Accept", "application/vnd.github+json")
-        .header("User-Agent", "Wildflover-Marketplace")
+        .header("User-Agent", get_user_agent())
         .json(&serde_json::json!({
             "message": commit_message,
             "tree": tree_response.sha,
@@ -268,7 +269,7 @@ pub async fn delete_marketplace_mod(
         .patch(format!("{}/git/refs/heads/main", api_base))
         .header("Authorization", format!("Bearer {}", github_token))
         .header("Accept", "application/vnd.github+json")
-        .header("User-Agent", "Wildflover-Marketplace")
+        .header("User-Agent", get_user_agent())
         .json(&serde_json::json!({
             "sha": commit_response.sha
         }))
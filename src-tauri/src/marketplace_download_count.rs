@@ -9,9 +9,7 @@
 use serde::Serialize;
 use reqwest::Client;
 use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
-use crate::marketplace::get_token;
-use std::sync::Arc;
-use tokio::sync::Mutex;
+use crate::marketplace::{get_token, get_user_agent, get_index_mutex, record_api_latency};
 
 // [STRUCT] Download count increment result
 #[derive(Serialize)]
@@ -19,19 +17,13 @@ pub struct IncrementResult {
     pub success: bool,
     pub new_count: Option<i64>,
     pub error: Option<String>,
+    pub api_ms: Option<u64>,
 }
 
 // [CONST] Retry configuration
 const MAX_RETRIES: u32 = 5;
 const RETRY_DELAY_MS: u64 = 600;
 
-// [STATIC] Global async mutex for sequential updates
-static UPDATE_MUTEX: std::sync::OnceLock<Arc<Mutex<()>>> = std::sync::OnceLock::new();
-
-fn get_update_mutex() -> Arc<Mutex<()>> {
-    UPDATE_MUTEX.get_or_init(|| Arc::new(Mutex::new(()))).clone()
-}
-
 // [COMMAND] Increment download count for mod in marketplace
 #[tauri::command]
 pub async fn increment_download_count(
@@ -41,23 +33,31 @@ pub async fn increment_download_count(
 ) -> IncrementResult {
     println!("[MARKETPLACE-DOWNLOAD-COUNT] Queued increment for mod: {}", mod_id);
     
-    // Acquire async lock to serialize all download count updates
-    let mutex = get_update_mutex();
+    // Acquire per-repo async lock to serialize index.json updates
+    let mutex = get_index_mutex(&github_owner, &github_repo);
     let _lock = mutex.lock().await;
     
     println!("[MARKETPLACE-DOWNLOAD-COUNT] Processing: {}", mod_id);
     
     let mut last_error = String::new();
+    // [LATENCY] Time inside GitHub calls only - lock wait and backoff sleeps excluded
+    let mut api_ms: u64 = 0;
     
     // Retry loop for handling SHA conflicts
     for attempt in 1..=MAX_RETRIES {
-        match try_increment_download_count(&mod_id, &github_owner, &github_repo).await {
+        let attempt_started = std::time::Instant::now();
+        let outcome = try_increment_download_count(&mod_id, &github_owner, &github_repo).await;
+        api_ms += attempt_started.elapsed().as_millis() as u64;
+        
+        match outcome {
             Ok(new_count) => {
                 println!("[MARKETPLACE-DOWNLOAD-COUNT] Success on attempt {}: {} -> {}", attempt, mod_id, new_count);
+                record_api_latency("download_count", api_ms, true);
                 return IncrementResult {
                     success: true,
                     new_count: Some(new_count),
                     error: None,
+                    api_ms: Some(api_ms),
                 };
             }
             Err(e) => {
@@ -80,10 +80,12 @@ pub async fn increment_download_count(
         }
     }
     
+    record_api_latency("download_count", api_ms, false);
     IncrementResult {
         success: false,
         new_count: None,
         error: Some(format!("Failed after {} attempts: {}", MAX_RETRIES, last_error)),
+        api_ms: Some(api_ms),
     }
 }
 
@@ -108,7 +110,7 @@ async fn try_increment_download_count(
         .get(&index_api_url)
         .header("Authorization", format!("Bearer {}", github_token))
         .header("Accept", "application/vnd.github+json")
-        .header("User-Agent", "Wildflover-Marketplace")
+        .header("User-Agent", get_user_agent())
         .header("X-GitHub-Api-Version", "2022-11-28")
         .header("Cache-Control", "no-cache")
         .send()
@@ -168,7 +170,7 @@ async fn try_increment_download_count(
         .put(&index_api_url)
         .header("Authorization", format!("Bearer {}", github_token))
         .header("Accept", "application/vnd.github+json")
-        .header("User-Agent", "Wildflover-Marketplace")
+        .header("User-Agent", get_user_agent())
         .header("X-GitHub-Api-Version", "2022-11-28")
         .json(&serde_json::json!({
             "message": format!("[MARKETPLACE] Download count: {} (+1)", mod_id),
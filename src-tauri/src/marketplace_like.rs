@@ -5,20 +5,24 @@
 //!              - Update likeCount and likedBy in index.json
 //!              - Retry mechanism for concurrent updates (SHA conflict handling)
 //!              - Queue-based sequential processing for atomic commits
+//!              - Debounced queue_like collapses rapid toggles into one commit
 //! Language: Rust
 
 use serde::{Deserialize, Serialize};
 use reqwest::Client;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tauri::{AppHandle, Emitter};
 use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
-use crate::marketplace::get_token;
-use std::sync::Arc;
-use tokio::sync::Mutex;
+use crate::marketplace::{get_token, get_user_agent, get_index_mutex, record_api_latency};
 
 // [STRUCT] Like operation result
 #[derive(Serialize)]
 pub struct LikeResult {
     pub success: bool,
     pub error: Option<String>,
+    pub api_ms: Option<u64>,
 }
 
 // [STRUCT] User info for like tracking
@@ -31,15 +35,94 @@ pub struct UserInfo {
     pub avatar: Option<String>,
 }
 
+// [STRUCT] Payload of the like-synced event
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LikeSyncedEvent {
+    pub mod_id: String,
+    pub like: bool,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
 // [CONST] Retry configuration
 const MAX_RETRIES: u32 = 5;
 const RETRY_DELAY_MS: u64 = 600;
 
-// [STATIC] Global async mutex for sequential like updates
-static LIKE_MUTEX: std::sync::OnceLock<Arc<Mutex<()>>> = std::sync::OnceLock::new();
+// [CONST] Quiet period before a queued like is committed
+const LIKE_DEBOUNCE_MS: u64 = 1500;
+
+// [STATE] Latest queued toggle per repo/mod - older toggles see a newer sequence and drop out
+static LIKE_QUEUE_SEQ: AtomicU64 = AtomicU64::new(0);
+
+lazy_static::lazy_static! {
+    static ref PENDING_LIKES: Mutex<HashMap<String, u64>> = Mutex::new(HashMap::new());
+}
 
-fn get_like_mutex() -> Arc<Mutex<()>> {
-    LIKE_MUTEX.get_or_init(|| Arc::new(Mutex::new(()))).clone()
+// [COMMAND] Debounced like/unlike - returns immediately, commits the final state in the background
+// Emits "like-synced" once the commit lands (or fails)
+#[tauri::command]
+pub async fn queue_like(
+    app: AppHandle,
+    mod_id: String,
+    like: bool,
+    user_info: Option<UserInfo>,
+    owner: String,
+    repo: String,
+) -> LikeResult {
+    if user_info.is_none() {
+        return LikeResult {
+            success: false,
+            error: Some("User info required for like operation".to_string()),
+            api_ms: None,
+        };
+    }
+    
+    let key = format!("{}/{}/{}", owner, repo, mod_id);
+    let seq = LIKE_QUEUE_SEQ.fetch_add(1, Ordering::SeqCst) + 1;
+    
+    if let Ok(mut pending) = PENDING_LIKES.lock() {
+        pending.insert(key.clone(), seq);
+    }
+    
+    println!("[MARKETPLACE-LIKE] Debounced {} for mod: {} (#{})", if like { "like" } else { "unlike" }, mod_id, seq);
+    
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(tokio::time::Duration::from_millis(LIKE_DEBOUNCE_MS)).await;
+        
+        // Only the last toggle in the quiet period gets committed
+        let is_latest = match PENDING_LIKES.lock() {
+            Ok(mut pending) => {
+                if pending.get(&key) == Some(&seq) {
+                    pending.remove(&key);
+                    true
+                } else {
+                    false
+                }
+            }
+            Err(_) => false,
+        };
+        
+        if !is_latest {
+            println!("[MARKETPLACE-LIKE] Superseded toggle dropped: {} (#{})", mod_id, seq);
+            return;
+        }
+        
+        let result = like_marketplace_mod(mod_id.clone(), like, user_info, owner, repo).await;
+        
+        let _ = app.emit("like-synced", LikeSyncedEvent {
+            mod_id,
+            like,
+            success: result.success,
+            error: result.error,
+        });
+    });
+    
+    LikeResult {
+        success: true,
+        error: None,
+        api_ms: None,
+    }
 }
 
 // [COMMAND] Like/Unlike mod in marketplace (updates GitHub index.json)
@@ -53,22 +136,30 @@ pub async fn like_marketplace_mod(
 ) -> LikeResult {
     println!("[MARKETPLACE-LIKE] Queued {} for mod: {}", if like { "like" } else { "unlike" }, mod_id);
     
-    // Acquire async lock to serialize all like updates
-    let mutex = get_like_mutex();
+    // Acquire per-repo async lock to serialize index.json updates
+    let mutex = get_index_mutex(&github_owner, &github_repo);
     let _lock = mutex.lock().await;
     
     println!("[MARKETPLACE-LIKE] Processing: {}", mod_id);
     
     let mut last_error = String::new();
+    // [LATENCY] Time inside GitHub calls only - lock wait and backoff sleeps excluded
+    let mut api_ms: u64 = 0;
     
     // Retry loop for handling SHA conflicts
     for attempt in 1..=MAX_RETRIES {
-        match try_like_mod(&mod_id, like, &user_info, &github_owner, &github_repo).await {
+        let attempt_started = std::time::Instant::now();
+        let outcome = try_like_mod(&mod_id, like, &user_info, &github_owner, &github_repo).await;
+        api_ms += attempt_started.elapsed().as_millis() as u64;
+        
+        match outcome {
             Ok(()) => {
                 println!("[MARKETPLACE-LIKE] Success on attempt {}: {}", attempt, mod_id);
+                record_api_latency("like", api_ms, true);
                 return LikeResult {
                     success: true,
                     error: None,
+                    api_ms: Some(api_ms),
                 };
             }
             Err(e) => {
@@ -91,9 +182,11 @@ pub async fn like_marketplace_mod(
         }
     }
     
+    record_api_latency("like", api_ms, false);
     LikeResult {
         success: false,
         error: Some(format!("Failed after {} attempts: {}", MAX_RETRIES, last_error)),
+        api_ms: Some(api_ms),
     }
 }
 
@@ -120,7 +213,7 @@ async fn try_like_mod(
         .get(&index_api_url)
         .header("Authorization", format!("Bearer {}", github_token))
         .header("Accept", "application/vnd.github+json")
-        .header("User-Agent", "Wildflover-Marketplace")
+        .header("User-Agent", get_user_agent())
         .header("X-GitHub-Api-Version", "2022-11-28")
         .header("Cache-Control", "no-cache")
         .send()
@@ -168,6 +261,8 @@ async fn try_like_mod(
                         .and_then(|v| v.as_array_mut())
                         .unwrap();
                     
+                    let before_count = liked_by.len();
+                    
                     if like {
                         // Check if user already liked - prevent duplicate
                         let exists = liked_by.iter().any(|l| {
@@ -194,6 +289,15 @@ async fn try_like_mod(
                     
                     // Sync likeCount with actual likedBy array length
                     let actual_count = liked_by.len() as i64;
+                    let unchanged = liked_by.len() == before_count
+                        && mod_entry["likeCount"].as_i64() == Some(actual_count);
+                    
+                    // Already in the requested state - skip the empty commit
+                    if unchanged {
+                        println!("[MARKETPLACE-LIKE] {} already {} - no commit needed", mod_id, if like { "liked" } else { "unliked" });
+                        return Ok(());
+                    }
+                    
                     mod_entry["likeCount"] = serde_json::json!(actual_count);
                 } else {
                     // No user info - cannot track who liked, skip operation
@@ -219,7 +323,7 @@ async fn try_like_mod(
         .put(&index_api_url)
         .header("Authorization", format!("Bearer {}", github_token))
         .header("Accept", "application/vnd.github+json")
-        .header("User-Agent", "Wildflover-Marketplace")
+        .header("User-Agent", get_user_agent())
         .header("X-GitHub-Api-Version", "2022-11-28")
         .json(&serde_json::json!({
             "message": format!("[MARKETPLACE] {}: {}", if like { "Like" } else { "Unlike" }, mod_id),
@@ -7,7 +7,7 @@
 
 use serde::{Deserialize, Serialize};
 use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
-use crate::marketplace::get_token;
+use crate::marketplace::{get_token, get_user_agent};
 
 // [STRUCT] Update request data
 #[derive(Debug, Deserialize)]
@@ -67,7 +67,7 @@ pub async fn update_marketplace_mod(
     let index_response = match client
         .get(&index_url)
         .header("Authorization", format!("Bearer {}", github_token))
-        .header("User-Agent", "Wildflover-Marketplace")
+        .header("User-Agent", get_user_agent())
         .header("Accept", "application/vnd.github.v3+json")
         .send()
         .await
@@ -184,7 +184,7 @@ pub async fn update_marketplace_mod(
     let update_response = match client
         .put(&index_url)
         .header("Authorization", format!("Bearer {}", github_token))
-        .header("User-Agent", "Wildflover-Marketplace")
+        .header("User-Agent", get_user_agent())
         .header("Accept", "application/vnd.github.v3+json")
         .json(&update_request)
         .send()
@@ -224,7 +224,7 @@ pub async fn update_marketplace_mod(
         let preview_response = client
             .get(&preview_url)
             .header("Authorization", format!("Bearer {}", github_token))
-            .header("User-Agent", "Wildflover-Marketplace")
+            .header("User-Agent", get_user_agent())
             .header("Accept", "application/vnd.github.v3+json")
             .send()
             .await;
@@ -264,7 +264,7 @@ pub async fn update_marketplace_mod(
         let preview_result = client
             .put(&preview_url)
             .header("Authorization", format!("Bearer {}", github_token))
-            .header("User-Agent", "Wildflover-Marketplace")
+            .header("User-Agent", get_user_agent())
             .header("Accept", "application/vnd.github.v3+json")
             .json(&preview_update)
             .send()
@@ -274,6 +274,7 @@ pub async fn update_marketplace_mod(
             Ok(resp) => {
                 if resp.status().is_success() {
                     println!("[MARKETPLACE-UPDATE] Preview image updated successfully");
+                    crate::marketplace::invalidate_cached_preview(&mod_id);
                     preview_updated = true;
                 } else {
                     let status = resp.status();
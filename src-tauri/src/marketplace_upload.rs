@@ -10,14 +10,14 @@
 use serde::{Deserialize, Serialize};
 use reqwest::Client;
 use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
-use crate::marketplace::get_token;
+use crate::marketplace::{get_token, get_user_agent, record_api_latency};
 use crate::marketplace_catalog::{
     GitHubBlobResponse, GitHubTreeItem, GitHubTreeResponse,
     GitHubCommitResponse, GitHubRefResponse,
 };
 
 // [STRUCT] Upload metadata from frontend
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UploadMetadata {
     pub name: String,
@@ -37,6 +37,87 @@ pub struct UploadResult {
     pub mod_id: Option<String>,
     pub commit_url: Option<String>,
     pub error: Option<String>,
+    pub verified: Option<bool>,
+    pub api_ms: Option<u64>,
+    pub verify_ms: Option<u64>,
+}
+
+// [CONST] Metadata limits - keep the shared catalog clean
+const MAX_NAME_LEN: usize = 80;
+const MAX_DESCRIPTION_LEN: usize = 2000;
+const MAX_TAGS: usize = 10;
+const MAX_TAG_LEN: usize = 30;
+
+// [CONST] Error code prefix for rejected metadata
+const INVALID_METADATA: &str = "INVALID_METADATA";
+
+// [CONST] Post-upload availability polling
+const VERIFY_MAX_ATTEMPTS: u32 = 6;
+const VERIFY_INITIAL_DELAY_MS: u64 = 1000;
+const VERIFY_MAX_DELAY_MS: u64 = 8000;
+
+// [FUNC] Trim and strip control characters - newlines/tabs kept only where allowed
+fn clean_text(value: &str, allow_newlines: bool) -> String {
+    value
+        .trim()
+        .chars()
+        .filter(|c| !c.is_control() || (allow_newlines && (*c == '\n' || *c == '\t')))
+        .collect()
+}
+
+// [FUNC] Sanitize upload metadata and collect every validation problem
+fn sanitize_metadata(metadata: UploadMetadata) -> Result<UploadMetadata, String> {
+    let mut problems: Vec<String> = Vec::new();
+    
+    let cleaned = UploadMetadata {
+        name: clean_text(&metadata.name, false),
+        author: clean_text(&metadata.author, false),
+        author_id: clean_text(&metadata.author_id, false),
+        author_avatar: metadata.author_avatar.map(|a| clean_text(&a, false)).filter(|a| !a.is_empty()),
+        description: clean_text(&metadata.description, true),
+        title: clean_text(&metadata.title, false),
+        tags: metadata.tags.iter()
+            .map(|t| clean_text(t, false))
+            .filter(|t| !t.is_empty())
+            .collect(),
+        version: clean_text(&metadata.version, false),
+    };
+    
+    for (field, value) in [
+        ("name", &cleaned.name),
+        ("author", &cleaned.author),
+        ("title", &cleaned.title),
+        ("version", &cleaned.version),
+    ] {
+        if value.is_empty() {
+            problems.push(format!("{} is required", field));
+        }
+    }
+    
+    if cleaned.name.chars().count() > MAX_NAME_LEN {
+        problems.push(format!("name exceeds {} characters", MAX_NAME_LEN));
+    }
+    if cleaned.description.chars().count() > MAX_DESCRIPTION_LEN {
+        problems.push(format!("description exceeds {} characters", MAX_DESCRIPTION_LEN));
+    }
+    if cleaned.tags.len() > MAX_TAGS {
+        problems.push(format!("too many tags ({} > {})", cleaned.tags.len(), MAX_TAGS));
+    }
+    for tag in cleaned.tags.iter().filter(|t| t.chars().count() > MAX_TAG_LEN) {
+        problems.push(format!("tag '{}' exceeds {} characters", tag, MAX_TAG_LEN));
+    }
+    
+    if problems.is_empty() {
+        Ok(cleaned)
+    } else {
+        Err(format!("{}: {}", INVALID_METADATA, problems.join("; ")))
+    }
+}
+
+// [COMMAND] Validate upload metadata - returns the sanitized copy that would be committed
+#[tauri::command]
+pub async fn validate_upload_metadata(metadata: UploadMetadata) -> Result<UploadMetadata, String> {
+    sanitize_metadata(metadata)
 }
 
 // [FUNC] Generate unique mod ID from name
@@ -58,6 +139,7 @@ fn generate_mod_id(name: &str) -> String {
 
 
 // [COMMAND] Upload mod to GitHub marketplace (admin only)
+// api_ms covers only the GitHub calls - the optional verification wait is reported as verify_ms
 #[tauri::command]
 pub async fn upload_marketplace_mod(
     metadata: UploadMetadata,
@@ -66,8 +148,58 @@ pub async fn upload_marketplace_mod(
     _github_token: String,
     github_owner: String,
     github_repo: String,
+    verify: Option<bool>,
+) -> UploadResult {
+    let mut api_ms: u64 = 0;
+    let mut result = run_upload(metadata, file_path, preview_path, &github_owner, &github_repo, &mut api_ms).await;
+    
+    record_api_latency("upload", api_ms, result.success);
+    result.api_ms = Some(api_ms);
+    
+    // [VERIFY] Optional - wait until the new mod file is served, timed apart from the upload
+    if result.success && verify.unwrap_or(false) {
+        if let Some(ref mod_id) = result.mod_id {
+            let client = Client::builder()
+                .timeout(std::time::Duration::from_secs(30))
+                .build()
+                .unwrap_or_else(|_| Client::new());
+            let verify_started = std::time::Instant::now();
+            result.verified = Some(wait_until_fetchable(&client, &get_token(), &github_owner, &github_repo, mod_id).await);
+            result.verify_ms = Some(verify_started.elapsed().as_millis() as u64);
+        }
+    }
+    
+    result
+}
+
+// [FUNC] Upload pipeline - validate, create blobs/tree/commit, update index.json
+// Time spent waiting on GitHub is added to api_ms
+async fn run_upload(
+    metadata: UploadMetadata,
+    file_path: String,
+    preview_path: Option<String>,
+    github_owner: &str,
+    github_repo: &str,
+    api_ms: &mut u64,
 ) -> UploadResult {
     println!("[MARKETPLACE-UPLOAD] Starting upload: {}", metadata.name);
+    
+    // [VALIDATE] Never commit unchecked frontend input to the shared catalog
+    let metadata = match sanitize_metadata(metadata) {
+        Ok(metadata) => metadata,
+        Err(e) => {
+            println!("[MARKETPLACE-UPLOAD] Rejected metadata: {}", e);
+            return UploadResult {
+                success: false,
+                mod_id: None,
+                commit_url: None,
+                error: Some(e),
+                verified: None,
+                api_ms: None,
+                verify_ms: None,
+            };
+        }
+    };
     println!("[MARKETPLACE-UPLOAD] Author: {} ({})", metadata.author, metadata.author_id);
     
     let github_token = get_token();
@@ -91,6 +223,9 @@ pub async fn upload_marketplace_mod(
                 mod_id: None,
                 commit_url: None,
                 error: Some(format!("Failed to read mod file: {}", e)),
+                verified: None,
+                api_ms: None,
+                verify_ms: None,
             };
         }
     };
@@ -99,20 +234,27 @@ pub async fn upload_marketplace_mod(
     let file_size = mod_bytes.len() as u64;
     println!("[MARKETPLACE-UPLOAD] Mod file size: {} bytes", file_size);
     
+    // [INTEGRITY] SHA-256 published alongside the mod so downloads can be verified
+    // Hashed from the bytes already in memory - no second read of the file
+    let file_hash = crate::file_hash::hash_bytes(&mod_bytes);
+    println!("[MARKETPLACE-UPLOAD] Mod SHA-256: {}", file_hash);
+    
     // [STEP-2] Create blob for mod file
     println!("[MARKETPLACE-UPLOAD] Creating blob for mod file...");
-    let blob_response = match client
+    let call_started = std::time::Instant::now();
+    let sent = client
         .post(format!("{}/git/blobs", api_base))
         .header("Authorization", format!("Bearer {}", github_token))
         .header("Accept", "application/vnd.github+json")
-        .header("User-Agent", "Wildflover-Marketplace")
+        .header("User-Agent", get_user_agent())
         .json(&serde_json::json!({
             "content": mod_base64,
             "encoding": "base64"
         }))
         .send()
-        .await
-    {
+        .await;
+    *api_ms += call_started.elapsed().as_millis() as u64;
+    let blob_response = match sent {
         Ok(resp) => {
             if !resp.status().is_success() {
                 let status = resp.status();
@@ -122,6 +264,9 @@ pub async fn upload_marketplace_mod(
                     mod_id: None,
                     commit_url: None,
                     error: Some(format!("GitHub API error (blob): {} - {}", status, body)),
+                    verified: None,
+                    api_ms: None,
+                    verify_ms: None,
                 };
             }
             resp.json::<GitHubBlobResponse>().await.unwrap()
@@ -132,6 +277,9 @@ pub async fn upload_marketplace_mod(
                 mod_id: None,
                 commit_url: None,
                 error: Some(format!("Failed to create blob: {}", e)),
+                verified: None,
+                api_ms: None,
+                verify_ms: None,
             };
         }
     };
@@ -151,6 +299,7 @@ pub async fn upload_marketplace_mod(
         "tags": metadata.tags,
         "version": metadata.version,
         "fileSize": file_size,
+        "sha256": file_hash,
         "downloadCount": 0,
         "likeCount": 0,
         "createdAt": chrono::Utc::now().to_rfc3339(),
@@ -160,18 +309,20 @@ pub async fn upload_marketplace_mod(
     let info_base64 = BASE64.encode(serde_json::to_string_pretty(&info_json).unwrap().as_bytes());
     
     println!("[MARKETPLACE-UPLOAD] Creating blob for info.json...");
-    let info_blob_response = match client
+    let call_started = std::time::Instant::now();
+    let sent = client
         .post(format!("{}/git/blobs", api_base))
         .header("Authorization", format!("Bearer {}", github_token))
         .header("Accept", "application/vnd.github+json")
-        .header("User-Agent", "Wildflover-Marketplace")
+        .header("User-Agent", get_user_agent())
         .json(&serde_json::json!({
             "content": info_base64,
             "encoding": "base64"
         }))
         .send()
-        .await
-    {
+        .await;
+    *api_ms += call_started.elapsed().as_millis() as u64;
+    let info_blob_response = match sent {
         Ok(resp) => resp.json::<GitHubBlobResponse>().await.unwrap(),
         Err(e) => {
             return UploadResult {
@@ -179,6 +330,9 @@ pub async fn upload_marketplace_mod(
                 mod_id: None,
                 commit_url: None,
                 error: Some(format!("Failed to create info blob: {}", e)),
+                verified: None,
+                api_ms: None,
+                verify_ms: None,
             };
         }
     };
@@ -193,18 +347,20 @@ pub async fn upload_marketplace_mod(
             if let Ok(preview_bytes) = std::fs::read(preview) {
                 let preview_base64 = BASE64.encode(&preview_bytes);
                 
-                if let Ok(resp) = client
+                let call_started = std::time::Instant::now();
+                let sent = client
                     .post(format!("{}/git/blobs", api_base))
                     .header("Authorization", format!("Bearer {}", github_token))
                     .header("Accept", "application/vnd.github+json")
-                    .header("User-Agent", "Wildflover-Marketplace")
+                    .header("User-Agent", get_user_agent())
                     .json(&serde_json::json!({
                         "content": preview_base64,
                         "encoding": "base64"
                     }))
                     .send()
-                    .await
-                {
+                    .await;
+                *api_ms += call_started.elapsed().as_millis() as u64;
+                if let Ok(resp) = sent {
                     if let Ok(blob) = resp.json::<GitHubBlobResponse>().await {
                         preview_blob_sha = Some(blob.sha);
                         println!("[MARKETPLACE-UPLOAD] Preview blob created");
@@ -216,14 +372,16 @@ pub async fn upload_marketplace_mod(
     
     // [STEP-5] Get current main branch SHA
     println!("[MARKETPLACE-UPLOAD] Getting current branch SHA...");
-    let ref_response = match client
+    let call_started = std::time::Instant::now();
+    let sent = client
         .get(format!("{}/git/ref/heads/main", api_base))
         .header("Authorization", format!("Bearer {}", github_token))
         .header("Accept", "application/vnd.github+json")
-        .header("User-Agent", "Wildflover-Marketplace")
+        .header("User-Agent", get_user_agent())
         .send()
-        .await
-    {
+        .await;
+    *api_ms += call_started.elapsed().as_millis() as u64;
+    let ref_response = match sent {
         Ok(resp) => resp.json::<GitHubRefResponse>().await.unwrap(),
         Err(e) => {
             return UploadResult {
@@ -231,6 +389,9 @@ pub async fn upload_marketplace_mod(
                 mod_id: None,
                 commit_url: None,
                 error: Some(format!("Failed to get branch ref: {}", e)),
+                verified: None,
+                api_ms: None,
+                verify_ms: None,
             };
         }
     };
@@ -264,21 +425,23 @@ pub async fn upload_marketplace_mod(
     }
     
     // [STEP-6.5] Fetch and update index.json
-    update_index_json(&client, &github_token, &github_owner, &github_repo, &mod_id, &metadata, file_size, &mut tree_items).await;
+    update_index_json(&client, &github_token, github_owner, github_repo, &mod_id, &metadata, file_size, Some(&file_hash), &mut tree_items, api_ms).await;
     
     println!("[MARKETPLACE-UPLOAD] Creating tree with {} items...", tree_items.len());
-    let tree_response = match client
+    let call_started = std::time::Instant::now();
+    let sent = client
         .post(format!("{}/git/trees", api_base))
         .header("Authorization", format!("Bearer {}", github_token))
         .header("Accept", "application/vnd.github+json")
-        .header("User-Agent", "Wildflover-Marketplace")
+        .header("User-Agent", get_user_agent())
         .json(&serde_json::json!({
             "base_tree": base_sha,
             "tree": tree_items
         }))
         .send()
-        .await
-    {
+        .await;
+    *api_ms += call_started.elapsed().as_millis() as u64;
+    let tree_response = match sent {
         Ok(resp) => resp.json::<GitHubTreeResponse>().await.unwrap(),
         Err(e) => {
             return UploadResult {
@@ -286,6 +449,9 @@ pub async fn upload_marketplace_mod(
                 mod_id: None,
                 commit_url: None,
                 error: Some(format!("Failed to create tree: {}", e)),
+                verified: None,
+                api_ms: None,
+                verify_ms: None,
             };
         }
     };
@@ -296,19 +462,21 @@ pub async fn upload_marketplace_mod(
     println!("[MARKETPLACE-UPLOAD] Creating commit...");
     let commit_message = format!("[MARKETPLACE] Add mod: {} by {}", metadata.name, metadata.author);
     
-    let commit_response = match client
+    let call_started = std::time::Instant::now();
+    let sent = client
         .post(format!("{}/git/commits", api_base))
         .header("Authorization", format!("Bearer {}", github_token))
         .header("Accept", "application/vnd.github+json")
-        .header("User-Agent", "Wildflover-Marketplace")
+        .header("User-Agent", get_user_agent())
         .json(&serde_json::json!({
             "message": commit_message,
             "tree": tree_sha,
             "parents": [base_sha]
         }))
         .send()
-        .await
-    {
+        .await;
+    *api_ms += call_started.elapsed().as_millis() as u64;
+    let commit_response = match sent {
         Ok(resp) => resp.json::<GitHubCommitResponse>().await.unwrap(),
         Err(e) => {
             return UploadResult {
@@ -316,6 +484,9 @@ pub async fn upload_marketplace_mod(
                 mod_id: None,
                 commit_url: None,
                 error: Some(format!("Failed to create commit: {}", e)),
+                verified: None,
+                api_ms: None,
+                verify_ms: None,
             };
         }
     };
@@ -325,17 +496,19 @@ pub async fn upload_marketplace_mod(
     
     // [STEP-8] Update branch reference
     println!("[MARKETPLACE-UPLOAD] Updating branch reference...");
-    match client
+    let call_started = std::time::Instant::now();
+    let sent = client
         .patch(format!("{}/git/refs/heads/main", api_base))
         .header("Authorization", format!("Bearer {}", github_token))
         .header("Accept", "application/vnd.github+json")
-        .header("User-Agent", "Wildflover-Marketplace")
+        .header("User-Agent", get_user_agent())
         .json(&serde_json::json!({
             "sha": commit_sha
         }))
         .send()
-        .await
-    {
+        .await;
+    *api_ms += call_started.elapsed().as_millis() as u64;
+    match sent {
         Ok(resp) => {
             if !resp.status().is_success() {
                 return UploadResult {
@@ -343,6 +516,9 @@ pub async fn upload_marketplace_mod(
                     mod_id: None,
                     commit_url: None,
                     error: Some("Failed to update branch reference".to_string()),
+                    verified: None,
+                    api_ms: None,
+                    verify_ms: None,
                 };
             }
         }
@@ -352,6 +528,9 @@ pub async fn upload_marketplace_mod(
                 mod_id: None,
                 commit_url: None,
                 error: Some(format!("Failed to update ref: {}", e)),
+                verified: None,
+                api_ms: None,
+                verify_ms: None,
             };
         }
     }
@@ -364,9 +543,62 @@ pub async fn upload_marketplace_mod(
         mod_id: Some(mod_id),
         commit_url: Some(commit_url),
         error: None,
+        verified: None,
+        api_ms: None,
+        verify_ms: None,
     }
 }
 
+// [FUNC] Poll Contents API until mods/{mod_id}/mod.fantome is available - bounded retries with backoff
+async fn wait_until_fetchable(
+    client: &Client,
+    github_token: &str,
+    github_owner: &str,
+    github_repo: &str,
+    mod_id: &str,
+) -> bool {
+    let file_url = format!(
+        "https://api.github.com/repos/{}/{}/contents/mods/{}/mod.fantome",
+        github_owner, github_repo, mod_id
+    );
+    
+    let mut delay_ms = VERIFY_INITIAL_DELAY_MS;
+    
+    for attempt in 1..=VERIFY_MAX_ATTEMPTS {
+        let response = client
+            .get(&file_url)
+            .header("Authorization", format!("Bearer {}", github_token))
+            .header("Accept", "application/vnd.github+json")
+            .header("User-Agent", get_user_agent())
+            .header("X-GitHub-Api-Version", "2022-11-28")
+            .send()
+            .await;
+        
+        match response {
+            Ok(resp) if resp.status().is_success() => {
+                println!("[MARKETPLACE-UPLOAD] Verified mod is fetchable (attempt {})", attempt);
+                return true;
+            }
+            Ok(resp) => {
+                println!("[MARKETPLACE-UPLOAD] Not yet available (attempt {}/{}): HTTP {}",
+                         attempt, VERIFY_MAX_ATTEMPTS, resp.status());
+            }
+            Err(e) => {
+                println!("[MARKETPLACE-UPLOAD] Verify request failed (attempt {}/{}): {}",
+                         attempt, VERIFY_MAX_ATTEMPTS, e);
+            }
+        }
+        
+        if attempt < VERIFY_MAX_ATTEMPTS {
+            tokio::time::sleep(std::time::Duration::from_millis(delay_ms)).await;
+            delay_ms = (delay_ms * 2).min(VERIFY_MAX_DELAY_MS);
+        }
+    }
+    
+    println!("[MARKETPLACE-UPLOAD] WARN: Mod not fetchable after {} attempts", VERIFY_MAX_ATTEMPTS);
+    false
+}
+
 
 // [FUNC] Update index.json with new mod entry
 async fn update_index_json(
@@ -377,21 +609,25 @@ async fn update_index_json(
     mod_id: &str,
     metadata: &UploadMetadata,
     file_size: u64,
+    file_hash: Option<&str>,
     tree_items: &mut Vec<GitHubTreeItem>,
+    api_ms: &mut u64,
 ) {
     let api_base = format!("https://api.github.com/repos/{}/{}", github_owner, github_repo);
     let index_api_url = format!("{}/contents/index.json", api_base);
     
     println!("[MARKETPLACE-UPLOAD] Fetching current index.json via API...");
     
+    let call_started = std::time::Instant::now();
     let index_response = client
         .get(&index_api_url)
         .header("Authorization", format!("Bearer {}", github_token))
         .header("Accept", "application/vnd.github.raw+json")
-        .header("User-Agent", "Wildflover-Marketplace")
+        .header("User-Agent", get_user_agent())
         .header("X-GitHub-Api-Version", "2022-11-28")
         .send()
         .await;
+    *api_ms += call_started.elapsed().as_millis() as u64;
     
     if let Ok(resp) = index_response {
         if resp.status().is_success() {
@@ -422,6 +658,7 @@ async fn update_index_json(
                         "tags": metadata.tags,
                         "version": metadata.version,
                         "fileSize": file_size,
+                        "sha256": file_hash,
                         "downloadCount": 0,
                         "likeCount": 0,
                         "downloadUrl": download_url,
@@ -440,18 +677,20 @@ async fn update_index_json(
                         let updated_index = serde_json::to_string_pretty(&index_json).unwrap();
                         let index_base64 = BASE64.encode(updated_index.as_bytes());
                         
-                        if let Ok(blob_resp) = client
+                        let call_started = std::time::Instant::now();
+                        let sent = client
                             .post(format!("{}/git/blobs", api_base))
                             .header("Authorization", format!("Bearer {}", github_token))
                             .header("Accept", "application/vnd.github+json")
-                            .header("User-Agent", "Wildflover-Marketplace")
+                            .header("User-Agent", get_user_agent())
                             .json(&serde_json::json!({
                                 "content": index_base64,
                                 "encoding": "base64"
                             }))
                             .send()
-                            .await
-                        {
+                            .await;
+                        *api_ms += call_started.elapsed().as_millis() as u64;
+                        if let Ok(blob_resp) = sent {
                             if let Ok(blob) = blob_resp.json::<GitHubBlobResponse>().await {
                                 tree_items.push(GitHubTreeItem {
                                     path: "index.json".to_string(),
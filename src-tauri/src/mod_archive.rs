@@ -0,0 +1,717 @@
+//! File: mod_archive.rs
+//! Author: Wildflover
+//! Description: Mod archive inspection without extraction
+//!              - Content profile: WADs per champion, asset type breakdown
+//!              - Script (.luabin) presence detection
+//!              - Central directory validity pre-check
+//!              - Mod structure check for file selection
+//!              - Champion spread summary for an activation set
+//!              - Declared champion check against WAD contents
+//!              - Game files an activation will redirect
+//!              - Dry-run fantome/zip structure validation
+//!              - Typed META/info.json reader for folders and archives
+//! Language: Rust
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use zip::ZipArchive;
+use crate::mod_manager::ModItem;
+
+// [CONST] WAD v3 header: magic(2) + version(2) + signature(256) + checksum(8) + entry count(4)
+const WAD_V3_ENTRY_COUNT_OFFSET: usize = 268;
+const WAD_HEADER_READ_SIZE: usize = 272;
+
+// [STRUCT] Count and total size of one asset category
+#[derive(Serialize, Default, Clone)]
+pub struct CategoryStats {
+    pub count: usize,
+    pub size: u64,
+}
+
+// [STRUCT] Single WAD inside a mod
+#[derive(Serialize)]
+pub struct WadSummary {
+    pub name: String,
+    pub champion: String,
+    pub size: u64,
+    pub entry_count: Option<u32>,
+}
+
+// [STRUCT] WADs grouped per champion
+#[derive(Serialize)]
+pub struct ChampionWads {
+    pub champion: String,
+    pub wad_count: usize,
+    pub total_size: u64,
+}
+
+// [STRUCT] Mod content profile
+#[derive(Serialize, Default)]
+pub struct ModAnalysis {
+    pub total_files: usize,
+    pub total_size: u64,
+    pub wads: Vec<WadSummary>,
+    pub champions: Vec<ChampionWads>,
+    pub textures: CategoryStats,
+    pub models: CategoryStats,
+    pub animations: CategoryStats,
+    pub sounds: CategoryStats,
+    pub bins: CategoryStats,
+    pub scripts: CategoryStats,
+    pub other: CategoryStats,
+    pub has_scripts: bool,
+}
+
+// [STRUCT] Fantome metadata from META/info.json - PascalCase keys on disk
+#[derive(Serialize, Deserialize, Default)]
+pub struct ModInfo {
+    #[serde(rename(deserialize = "Name"), default)]
+    pub name: Option<String>,
+    #[serde(rename(deserialize = "Author"), default)]
+    pub author: Option<String>,
+    #[serde(rename(deserialize = "Version"), default)]
+    pub version: Option<String>,
+    #[serde(rename(deserialize = "Description"), default)]
+    pub description: Option<String>,
+}
+
+// [CONST] Champion count above which an activation set is flagged as unusually large
+const LARGE_CHAMPION_SET: usize = 20;
+
+// [STRUCT] Mods touching a single champion
+#[derive(Serialize)]
+pub struct ChampionUsage {
+    pub champion: String,
+    pub mod_count: usize,
+}
+
+// [STRUCT] Advisory summary of an activation set
+#[derive(Serialize, Default)]
+pub struct ActivationSummary {
+    pub champions: Vec<ChampionUsage>,
+    pub champion_count: usize,
+    pub has_tft_content: bool,
+    pub has_sr_content: bool,
+    pub exceeds_limit: bool,
+    pub unreadable_mods: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+// [STRUCT] Champion found in a mod's WADs
+#[derive(Serialize)]
+pub struct DetectedChampion {
+    pub key: String,
+    pub champion_id: Option<i32>,
+    pub name: Option<String>,
+}
+
+// [STRUCT] Declared vs actual champion of a mod
+#[derive(Serialize)]
+pub struct ChampionVerification {
+    pub champions: Vec<DetectedChampion>,
+    pub expected_champion_id: Option<i32>,
+    pub matches: Option<bool>,
+    pub champion_data_loaded: bool,
+}
+
+// [STRUCT] Game WAD an activation redirects to modded content
+#[derive(Serialize)]
+pub struct GameWritePath {
+    pub mod_name: String,
+    pub wad: String,
+    pub game_file: Option<String>,
+}
+
+// [STRUCT] Dry-run archive validation report
+#[derive(Serialize, Default)]
+pub struct ValidationResult {
+    pub valid: bool,
+    pub has_wad: bool,
+    pub has_meta: bool,
+    pub entry_count: usize,
+    pub issues: Vec<String>,
+}
+
+// [CONST] Error code for downloads whose ZIP structure is unreadable
+pub const CORRUPT_ARCHIVE: &str = "CORRUPT_ARCHIVE";
+
+// [FUNC] Open archive and read its central directory without extracting anything
+pub fn archive_is_valid(path: &Path) -> bool {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(_) => return false,
+    };
+
+    match ZipArchive::new(file) {
+        // Touch every entry header so truncated directories are caught as well
+        Ok(mut archive) => (0..archive.len()).all(|i| archive.by_index_raw(i).is_ok()),
+        Err(e) => {
+            println!("[MOD-ARCHIVE] Corrupt archive {:?}: {}", path, e);
+            false
+        }
+    }
+}
+
+// [COMMAND] Quick archive integrity check - central directory only
+#[tauri::command]
+pub async fn is_valid_archive(path: String) -> bool {
+    archive_is_valid(Path::new(&path))
+}
+
+// [FUNC] Count .wad.client files anywhere under a mod folder
+pub fn count_wad_files(dir: &Path) -> usize {
+    std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .map(|entry| {
+                    let path = entry.path();
+                    if path.is_dir() {
+                        count_wad_files(&path)
+                    } else if entry.file_name().to_string_lossy().to_lowercase().ends_with(".wad.client") {
+                        1
+                    } else {
+                        0
+                    }
+                })
+                .sum()
+        })
+        .unwrap_or(0)
+}
+
+// [FUNC] Check a selected file is a usable mod - Err carries the reason shown in the UI
+pub fn validate_mod_file(path: &Path) -> Result<(), String> {
+    if path.is_dir() {
+        return if path.join("WAD").is_dir() {
+            Ok(())
+        } else {
+            Err("Folder has no WAD directory".to_string())
+        };
+    }
+
+    let lower = path.to_string_lossy().to_lowercase();
+
+    // [WAD] Raw WAD files only need a valid header magic
+    if lower.ends_with(".wad.client") || lower.ends_with(".wad") {
+        let mut magic = [0u8; 2];
+        return match File::open(path).and_then(|mut f| f.read_exact(&mut magic)) {
+            Ok(()) if &magic == b"RW" => Ok(()),
+            Ok(()) => Err("Not a WAD file".to_string()),
+            Err(e) => Err(format!("Failed to read file: {}", e)),
+        };
+    }
+
+    // [ARCHIVE] .zip/.fantome must contain a WAD/ folder
+    let file = File::open(path).map_err(|e| format!("Failed to open file: {}", e))?;
+    let archive = ZipArchive::new(file).map_err(|_| "Not a valid archive".to_string())?;
+    let has_wad = archive
+        .file_names()
+        .any(|name| name.replace('\\', "/").to_lowercase().starts_with("wad/"));
+
+    if has_wad {
+        Ok(())
+    } else {
+        Err("Archive has no WAD/ folder".to_string())
+    }
+}
+
+// [FUNC] Inspect a fantome/zip in memory - nothing is extracted or written
+fn inspect_mod_archive(path: &Path) -> ValidationResult {
+    let mut result = ValidationResult::default();
+
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(e) => {
+            result.issues.push(format!("Failed to open file: {}", e));
+            return result;
+        }
+    };
+    let mut archive = match ZipArchive::new(file) {
+        Ok(archive) => archive,
+        Err(e) => {
+            result.issues.push(format!("Not a valid zip: {}", e));
+            return result;
+        }
+    };
+
+    result.entry_count = archive.len();
+
+    for i in 0..archive.len() {
+        let mut entry = match archive.by_index(i) {
+            Ok(entry) => entry,
+            Err(e) => {
+                result.issues.push(format!("Unreadable entry #{}: {}", i, e));
+                continue;
+            }
+        };
+        let name = entry.name().replace('\\', "/");
+        let lower = name.to_lowercase();
+
+        if lower.starts_with("wad/") || lower.ends_with(".wad.client") {
+            result.has_wad = true;
+        }
+
+        // [WAD] Packed WADs must start with the "RW" magic
+        if lower.ends_with(".wad.client") && !entry.is_dir() {
+            let mut magic = [0u8; 2];
+            if entry.read_exact(&mut magic).is_err() || &magic != b"RW" {
+                result.issues.push(format!("{} is not a valid WAD", name));
+            }
+        }
+
+        // [META] info.json must be parseable JSON
+        if lower == "meta/info.json" {
+            result.has_meta = true;
+            let mut content = String::new();
+            let parsed = entry
+                .read_to_string(&mut content)
+                .ok()
+                .and_then(|_| serde_json::from_str::<serde_json::Value>(content.trim_start_matches('\u{feff}')).ok());
+            if parsed.is_none() {
+                result.issues.push("META/info.json is not valid JSON".to_string());
+            }
+        }
+    }
+
+    if !result.has_wad {
+        result.issues.push("Archive has no WAD/ folder or .wad.client files".to_string());
+    }
+    if !result.has_meta {
+        result.issues.push("Archive has no META/info.json".to_string());
+    }
+
+    result.valid = result.has_wad && result.has_meta && result.issues.is_empty();
+    result
+}
+
+// [FUNC] Parse META/info.json of a mod folder or fantome/zip - None when missing or malformed
+// Blank fields are normalized to None so callers can fall back to the folder name
+pub fn load_mod_info(path: &Path) -> Option<ModInfo> {
+    let content = if path.is_dir() {
+        std::fs::read_to_string(path.join("META").join("info.json")).ok()?
+    } else {
+        let mut archive = ZipArchive::new(File::open(path).ok()?).ok()?;
+        let index = (0..archive.len()).find(|&i| {
+            archive
+                .name_for_index(i)
+                .map(|name| name.replace('\\', "/").eq_ignore_ascii_case("meta/info.json"))
+                .unwrap_or(false)
+        })?;
+        let mut content = String::new();
+        archive.by_index(index).ok()?.read_to_string(&mut content).ok()?;
+        content
+    };
+
+    let mut info: ModInfo = serde_json::from_str(content.trim_start_matches('\u{feff}')).ok()?;
+    for field in [&mut info.name, &mut info.author, &mut info.version, &mut info.description] {
+        *field = field.take().map(|s| s.trim().to_string()).filter(|s| !s.is_empty());
+    }
+    Some(info)
+}
+
+// [COMMAND] Read name/author/version of a downloaded or custom mod
+// WAD-only mods without META/info.json return None instead of an error
+#[tauri::command]
+pub async fn read_mod_info(mod_path: String) -> Option<ModInfo> {
+    let info = load_mod_info(Path::new(&mod_path));
+    if info.is_none() {
+        println!("[MOD-ARCHIVE] No readable META/info.json in {}", mod_path);
+    }
+    info
+}
+
+// [COMMAND] Validate a fantome/zip without importing it
+#[tauri::command]
+pub async fn validate_mod_archive(path: String) -> ValidationResult {
+    let result = inspect_mod_archive(Path::new(&path));
+    println!("[MOD-ARCHIVE] Validated {}: valid {}, {} entries, {} issues",
+             path, result.valid, result.entry_count, result.issues.len());
+    result
+}
+
+// [FUNC] Champion/map name from a WAD file name (e.g. "WAD/Ahri.en_US.wad.client" -> "Ahri")
+fn champion_of_wad(name: &str) -> String {
+    let file_name = name.rsplit('/').next().unwrap_or(name);
+    let base = file_name
+        .trim_end_matches(".client")
+        .trim_end_matches(".wad");
+    base.split('.').next().unwrap_or(base).to_string()
+}
+
+// [FUNC] Read entry count from a WAD v3 header
+fn wad_entry_count(header: &[u8]) -> Option<u32> {
+    if header.len() < WAD_HEADER_READ_SIZE || &header[0..2] != b"RW" || header[2] != 3 {
+        return None;
+    }
+    let bytes = &header[WAD_V3_ENTRY_COUNT_OFFSET..WAD_V3_ENTRY_COUNT_OFFSET + 4];
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+// [FUNC] Classify a loose file by extension and add it to the matching category
+fn classify_file(name: &str, size: u64, analysis: &mut ModAnalysis) {
+    let lower = name.to_lowercase();
+    let ext = lower.rsplit('.').next().unwrap_or("");
+
+    let category = match ext {
+        "dds" | "tex" | "png" | "jpg" | "jpeg" | "tga" => &mut analysis.textures,
+        "skn" | "skl" | "scb" | "sco" | "mapgeo" => &mut analysis.models,
+        "anm" => &mut analysis.animations,
+        "bnk" | "wpk" | "wem" => &mut analysis.sounds,
+        "bin" => &mut analysis.bins,
+        "luabin" | "luabin64" | "lua" => {
+            analysis.has_scripts = true;
+            &mut analysis.scripts
+        }
+        _ => &mut analysis.other,
+    };
+
+    category.count += 1;
+    category.size += size;
+}
+
+// [FUNC] Record one file of the mod
+fn record_entry(name: &str, size: u64, header: &[u8], analysis: &mut ModAnalysis) {
+    analysis.total_files += 1;
+    analysis.total_size += size;
+
+    let lower = name.to_lowercase();
+    if lower.starts_with("meta/") {
+        return;
+    }
+
+    if lower.ends_with(".wad.client") || lower.ends_with(".wad") {
+        analysis.wads.push(WadSummary {
+            name: name.to_string(),
+            champion: champion_of_wad(name),
+            size,
+            entry_count: wad_entry_count(header),
+        });
+    } else {
+        classify_file(name, size, analysis);
+    }
+}
+
+// [FUNC] Walk a .fantome/.zip archive
+fn analyze_archive(path: &Path, analysis: &mut ModAnalysis) -> Result<(), String> {
+    let file = File::open(path).map_err(|e| format!("Failed to open mod: {}", e))?;
+    let mut archive = ZipArchive::new(file).map_err(|e| format!("Invalid archive: {}", e))?;
+
+    for i in 0..archive.len() {
+        let mut entry = match archive.by_index(i) {
+            Ok(entry) => entry,
+            Err(_) => continue,
+        };
+        if entry.is_dir() {
+            continue;
+        }
+
+        let name = entry.name().replace('\\', "/");
+        let size = entry.size();
+
+        let mut header = Vec::new();
+        let lower = name.to_lowercase();
+        if lower.ends_with(".wad.client") || lower.ends_with(".wad") {
+            let _ = (&mut entry).take(WAD_HEADER_READ_SIZE as u64).read_to_end(&mut header);
+        }
+
+        record_entry(&name, size, &header, analysis);
+    }
+
+    Ok(())
+}
+
+// [FUNC] Walk an extracted mod folder
+fn analyze_folder(root: &Path, dir: &Path, analysis: &mut ModAnalysis) {
+    if let Ok(entries) = std::fs::read_dir(dir) {
+        for entry in entries.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            if path.is_dir() {
+                analyze_folder(root, &path, analysis);
+                continue;
+            }
+
+            let name = path
+                .strip_prefix(root)
+                .map(|p| p.to_string_lossy().replace('\\', "/"))
+                .unwrap_or_default();
+            let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+
+            let mut header = Vec::new();
+            let lower = name.to_lowercase();
+            if lower.ends_with(".wad.client") || lower.ends_with(".wad") {
+                if let Ok(file) = File::open(&path) {
+                    let _ = file.take(WAD_HEADER_READ_SIZE as u64).read_to_end(&mut header);
+                }
+            }
+
+            record_entry(&name, size, &header, analysis);
+        }
+    }
+}
+
+// [FUNC] WAD groups that are maps or shared assets rather than champions
+fn is_non_champion_wad(name: &str) -> bool {
+    let lower = name.to_lowercase();
+    lower.starts_with("map") || lower.starts_with("common") || lower.starts_with("ui") || lower.starts_with("shipping")
+}
+
+// [FUNC] TFT content - Convergence map (Map22) or TFT-prefixed characters
+fn is_tft_wad(name: &str) -> bool {
+    let lower = name.to_lowercase();
+    lower.starts_with("map22") || lower.starts_with("tft")
+}
+
+// [COMMAND] Advisory check of an activation set - distinct champions and mixed TFT/SR content
+#[tauri::command]
+pub async fn summarize_activation(mods: Vec<ModItem>, max_champions: Option<usize>) -> ActivationSummary {
+    let mut summary = ActivationSummary::default();
+    let mut per_champion: BTreeMap<String, usize> = BTreeMap::new();
+
+    for mod_item in &mods {
+        let path = PathBuf::from(&mod_item.path);
+        let mut analysis = ModAnalysis::default();
+
+        if path.is_dir() {
+            analyze_folder(&path, &path, &mut analysis);
+        } else if analyze_archive(&path, &mut analysis).is_err() {
+            summary.unreadable_mods.push(mod_item.name.clone());
+            continue;
+        }
+
+        // Count each champion once per mod even if it ships several WADs for it
+        let mut champions: Vec<&str> = Vec::new();
+        for wad in &analysis.wads {
+            if is_tft_wad(&wad.champion) {
+                summary.has_tft_content = true;
+            } else if wad.champion.to_lowercase().starts_with("map11") {
+                summary.has_sr_content = true;
+            }
+            if is_non_champion_wad(&wad.champion) || champions.contains(&wad.champion.as_str()) {
+                continue;
+            }
+            champions.push(&wad.champion);
+        }
+
+        for champion in champions {
+            if !is_tft_wad(champion) {
+                summary.has_sr_content = true;
+            }
+            *per_champion.entry(champion.to_string()).or_default() += 1;
+        }
+    }
+
+    summary.champion_count = per_champion.len();
+    summary.champions = per_champion
+        .into_iter()
+        .map(|(champion, mod_count)| ChampionUsage { champion, mod_count })
+        .collect();
+
+    let limit = max_champions.unwrap_or(LARGE_CHAMPION_SET);
+    if summary.champion_count > limit {
+        summary.exceeds_limit = max_champions.is_some();
+        summary.warnings.push(format!(
+            "{} champions in one activation (limit {}) - large overlays are more likely to crash",
+            summary.champion_count, limit
+        ));
+    }
+    if summary.has_tft_content && summary.has_sr_content {
+        summary.warnings.push("TFT content mixed with Summoner's Rift mods".to_string());
+    }
+    if !summary.unreadable_mods.is_empty() {
+        summary.warnings.push(format!("{} mods could not be read", summary.unreadable_mods.len()));
+    }
+
+    println!("[MOD-ANALYZE] Activation summary: {} mods, {} champions, {} warnings",
+             mods.len(), summary.champion_count, summary.warnings.len());
+
+    summary
+}
+
+// [COMMAND] Check which champions a mod really modifies against the one it claims
+// matches is None without an expectation; true only when the expected champion is the sole one present
+#[tauri::command]
+pub async fn verify_mod_champion(path: String, expected_champion_id: Option<i32>) -> Result<ChampionVerification, String> {
+    let mod_path = PathBuf::from(&path);
+    if !mod_path.exists() {
+        return Err(format!("Mod not found: {}", path));
+    }
+
+    let mut analysis = ModAnalysis::default();
+    if mod_path.is_dir() {
+        analyze_folder(&mod_path, &mod_path, &mut analysis);
+    } else {
+        analyze_archive(&mod_path, &mut analysis)?;
+    }
+
+    let champion_data = crate::champion_data::load_champion_data();
+
+    let mut keys: Vec<String> = analysis.wads
+        .iter()
+        .map(|w| w.champion.clone())
+        .filter(|c| !is_non_champion_wad(c))
+        .collect();
+    keys.sort_by_key(|k| k.to_lowercase());
+    keys.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+
+    let champions: Vec<DetectedChampion> = keys
+        .into_iter()
+        .map(|key| {
+            let champion_id = champion_data
+                .as_ref()
+                .and_then(|data| crate::champion_data::champion_id_by_key(data, &key));
+            let name = champion_id
+                .and_then(|id| champion_data.as_ref().and_then(|data| data.champions.get(&id)))
+                .map(|c| c.name.clone());
+            DetectedChampion { key, champion_id, name }
+        })
+        .collect();
+
+    let matches = expected_champion_id.map(|expected| {
+        !champions.is_empty() && champions.iter().all(|c| c.champion_id == Some(expected))
+    });
+
+    println!("[MOD-ANALYZE] Champion check {}: {:?} (expected {:?}, matches {:?})",
+             path, champions.iter().map(|c| c.key.as_str()).collect::<Vec<_>>(), expected_champion_id, matches);
+
+    Ok(ChampionVerification {
+        champions,
+        expected_champion_id,
+        matches,
+        champion_data_loaded: champion_data.is_some(),
+    })
+}
+
+// [FUNC] WAD name from an entry path - "WAD/Ahri.wad.client" and folder-form "WAD/Ahri.wad.client/..." alike
+fn wad_name_of_entry(name: &str) -> Option<String> {
+    let mut parts = name.split('/');
+    if !parts.next()?.eq_ignore_ascii_case("wad") {
+        return None;
+    }
+    let wad = parts.next()?;
+    let lower = wad.to_lowercase();
+    (lower.ends_with(".wad.client") || lower.ends_with(".wad")).then(|| wad.to_string())
+}
+
+// [FUNC] Distinct WAD names shipped by a mod archive or extracted folder
+fn collect_mod_wads(path: &Path) -> Result<BTreeSet<String>, String> {
+    let mut wads = BTreeSet::new();
+
+    if path.is_dir() {
+        if let Ok(entries) = std::fs::read_dir(path.join("WAD")) {
+            for entry in entries.filter_map(|e| e.ok()) {
+                let name = format!("WAD/{}", entry.file_name().to_string_lossy());
+                wads.extend(wad_name_of_entry(&name));
+            }
+        }
+        return Ok(wads);
+    }
+
+    let file = File::open(path).map_err(|e| format!("Failed to open mod: {}", e))?;
+    let mut archive = ZipArchive::new(file).map_err(|e| format!("Invalid archive: {}", e))?;
+    for i in 0..archive.len() {
+        if let Ok(entry) = archive.by_index_raw(i) {
+            wads.extend(wad_name_of_entry(&entry.name().replace('\\', "/")));
+        }
+    }
+
+    Ok(wads)
+}
+
+// [FUNC] Index game WADs by lowercase file name -> path relative to the game folder
+fn index_game_wads(root: &Path, dir: &Path, index: &mut HashMap<String, String>) {
+    if let Ok(entries) = std::fs::read_dir(dir) {
+        for entry in entries.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            if path.is_dir() {
+                index_game_wads(root, &path, index);
+                continue;
+            }
+
+            let file_name = entry.file_name().to_string_lossy().to_lowercase();
+            if file_name.ends_with(".wad.client") || file_name.ends_with(".wad") {
+                let relative = path
+                    .strip_prefix(root)
+                    .map(|p| p.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                index.entry(file_name).or_insert(relative);
+            }
+        }
+    }
+}
+
+// [COMMAND] List the game WADs an activation would redirect - read-only, nothing is built
+// The overlay serves modded copies in place of these files; the install itself is not modified
+// game_file is None when the game has no WAD with that name (mod-tools skips it)
+#[tauri::command]
+pub async fn preview_game_writes(mods: Vec<ModItem>, game_path: String) -> Result<Vec<GameWritePath>, String> {
+    let game_dir = PathBuf::from(&game_path);
+    if !game_dir.join("League of Legends.exe").exists() {
+        return Err(format!("League of Legends.exe not found in: {}", game_path));
+    }
+
+    let mut game_wads: HashMap<String, String> = HashMap::new();
+    index_game_wads(&game_dir, &game_dir.join("DATA"), &mut game_wads);
+
+    let mut writes: Vec<GameWritePath> = Vec::new();
+    for mod_item in &mods {
+        let wads = match collect_mod_wads(Path::new(&mod_item.path)) {
+            Ok(wads) => wads,
+            Err(e) => {
+                println!("[MOD-ANALYZE] Skipping {} in write preview: {}", mod_item.name, e);
+                continue;
+            }
+        };
+
+        for wad in wads {
+            writes.push(GameWritePath {
+                mod_name: mod_item.name.clone(),
+                game_file: game_wads.get(&wad.to_lowercase()).cloned(),
+                wad,
+            });
+        }
+    }
+
+    println!("[MOD-ANALYZE] Write preview: {} WADs from {} mods ({} game WADs indexed)",
+             writes.len(), mods.len(), game_wads.len());
+
+    Ok(writes)
+}
+
+// [COMMAND] Summarize what a mod contains - WADs per champion and asset type breakdown
+#[tauri::command]
+pub async fn analyze_mod_contents(path: String) -> Result<ModAnalysis, String> {
+    println!("[MOD-ANALYZE] Analyzing: {}", path);
+
+    let mod_path = PathBuf::from(&path);
+    if !mod_path.exists() {
+        return Err(format!("Mod not found: {}", path));
+    }
+
+    let mut analysis = ModAnalysis::default();
+
+    if mod_path.is_dir() {
+        analyze_folder(&mod_path, &mod_path, &mut analysis);
+    } else {
+        analyze_archive(&mod_path, &mut analysis)?;
+    }
+
+    // [GROUP] Champion totals, sorted by name
+    let mut per_champion: BTreeMap<String, (usize, u64)> = BTreeMap::new();
+    for wad in &analysis.wads {
+        let totals = per_champion.entry(wad.champion.clone()).or_default();
+        totals.0 += 1;
+        totals.1 += wad.size;
+    }
+    analysis.champions = per_champion
+        .into_iter()
+        .map(|(champion, (wad_count, total_size))| ChampionWads { champion, wad_count, total_size })
+        .collect();
+
+    println!("[MOD-ANALYZE] {} files, {} WADs across {} champions, scripts: {}",
+             analysis.total_files, analysis.wads.len(), analysis.champions.len(), analysis.has_scripts);
+
+    Ok(analysis)
+}
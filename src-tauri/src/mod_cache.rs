@@ -0,0 +1,926 @@
+//! File: mod_cache.rs
+//! Author: Wildflover
+//! Description: Mod cache analysis and maintenance
+//!              - Duplicate file detection across cached mods
+//!              - Hardlink-based deduplication to reclaim disk space
+//!              - LRU eviction and scheduled cleanup under a size limit
+//!              - Orphaned download archive detection and removal
+//!              - Local search across cached mods
+//!              - Integrity check of cached skins and marketplace mods
+//!              - Per-champion summary of cached skins
+//!              - Installed mod listing with META/info.json details
+//! Language: Rust
+
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::SystemTime;
+use tauri::{AppHandle, Emitter};
+use crate::file_hash::hash_file;
+use crate::marketplace::get_marketplace_cache_dir;
+use crate::mod_manager::{get_mods_directory, get_overlay_directory, read_profile_mods, SkinDownloadRequest};
+use crate::settings::{load_settings, update_settings, AutoCacheCleanup};
+
+// [CONST] Files smaller than this are not worth deduplicating (META, small configs)
+const MIN_DEDUP_FILE_SIZE: u64 = 4096;
+
+// [CONST] Read buffer size for byte-for-byte comparison
+const HASH_CHUNK_SIZE: usize = 64 * 1024;
+
+// [CONST] Archives younger than this may belong to a download still being extracted
+const ORPHAN_MIN_AGE_SECS: u64 = 300;
+
+// [STATE] Cleanup task generation - bumping it stops the running timer
+static AUTO_CLEANUP_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+// [STRUCT] Group of identical cached files
+#[derive(Serialize)]
+pub struct DuplicateGroup {
+    pub hash: String,
+    pub size: u64,
+    pub files: Vec<String>,
+    pub reclaimable_bytes: u64,
+}
+
+// [STRUCT] Deduplication result
+#[derive(Serialize)]
+pub struct DedupeResult {
+    pub linked_files: usize,
+    pub reclaimed_bytes: u64,
+    pub errors: Vec<String>,
+}
+
+// [STRUCT] Cache eviction summary - also the payload of the cache-cleanup event
+#[derive(Serialize, Clone)]
+pub struct EvictionSummary {
+    pub evicted: Vec<String>,
+    pub freed_bytes: u64,
+    pub remaining_bytes: u64,
+    pub errors: Vec<String>,
+}
+
+// [STRUCT] Download archive without a usable extracted mod folder
+#[derive(Serialize)]
+pub struct OrphanInfo {
+    pub name: String,
+    pub path: String,
+    pub size: u64,
+}
+
+// [STRUCT] Orphaned archive cleanup result
+#[derive(Serialize)]
+pub struct OrphanCleanupResult {
+    pub deleted: usize,
+    pub reclaimed_bytes: u64,
+    pub errors: Vec<String>,
+}
+
+// [STRUCT] Cached mod folder matching a search
+#[derive(Serialize)]
+pub struct CacheSearchResult {
+    pub name: String,
+    pub location: String,
+    pub path: String,
+    pub size: u64,
+    pub champion: Option<String>,
+    pub title: Option<String>,
+}
+
+// [ENUM] Integrity state of a cached mod
+#[derive(Serialize, Clone, Copy, PartialEq)]
+pub enum CacheIntegrity {
+    Ok,
+    Corrupt,
+    Incomplete,
+}
+
+// [STRUCT] Integrity check result for one cached mod
+#[derive(Serialize)]
+pub struct CacheVerifyResult {
+    pub name: String,
+    pub location: String,
+    pub path: String,
+    pub status: CacheIntegrity,
+    pub detail: Option<String>,
+    pub redownloaded: bool,
+}
+
+// [STRUCT] Cached skins grouped under one champion
+#[derive(Serialize)]
+pub struct ChampionCacheSummary {
+    pub champion_id: i32,
+    pub name: Option<String>,
+    pub skin_count: usize,
+    pub total_size: u64,
+}
+
+// [STRUCT] Imported mod folder with its META/info.json details
+#[derive(Serialize)]
+pub struct InstalledMod {
+    pub folder: String,
+    pub path: String,
+    pub display_name: Option<String>,
+    pub author: Option<String>,
+    pub version: Option<String>,
+    pub size: u64,
+    pub is_marketplace: bool,
+    pub is_active: bool,
+}
+
+// [FUNC] Cache roots that hold mod content
+fn get_cache_roots() -> Vec<PathBuf> {
+    vec![
+        get_mods_directory(),
+        get_overlay_directory().join("installed"),
+        get_marketplace_cache_dir(),
+    ]
+}
+
+// [FUNC] Recursively collect regular files with their sizes
+fn collect_files(dir: &Path, files: &mut Vec<(PathBuf, u64)>) {
+    if let Ok(entries) = std::fs::read_dir(dir) {
+        for entry in entries.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            if path.is_dir() {
+                collect_files(&path, files);
+            } else if let Ok(meta) = entry.metadata() {
+                files.push((path, meta.len()));
+            }
+        }
+    }
+}
+
+// [FUNC] Drop paths that share a file identity with an earlier one - hardlinks take disk space once
+// Only same-size files large enough to have been deduplicated are opened, one size group at a time
+fn without_hardlinks(files: Vec<(PathBuf, u64)>) -> Vec<(PathBuf, u64)> {
+    let mut by_size: HashMap<u64, Vec<PathBuf>> = HashMap::new();
+    for (path, size) in files {
+        by_size.entry(size).or_default().push(path);
+    }
+
+    let mut distinct = Vec::new();
+    for (size, paths) in by_size {
+        if size < MIN_DEDUP_FILE_SIZE || paths.len() < 2 {
+            distinct.extend(paths.into_iter().map(|path| (path, size)));
+            continue;
+        }
+
+        let mut seen: HashSet<same_file::Handle> = HashSet::new();
+        for path in paths {
+            let linked = same_file::Handle::from_path(&path)
+                .map(|handle| !seen.insert(handle))
+                .unwrap_or(false);
+            if !linked {
+                distinct.push((path, size));
+            }
+        }
+    }
+    distinct
+}
+
+// [FUNC] Total size and most recent modification time of a cached mod folder
+fn folder_usage(dir: &Path) -> (u64, SystemTime) {
+    let mut files = Vec::new();
+    collect_files(dir, &mut files);
+    let files = without_hardlinks(files);
+
+    let last_used = files
+        .iter()
+        .filter_map(|(path, _)| std::fs::metadata(path).and_then(|m| m.modified()).ok())
+        .chain(std::fs::metadata(dir).and_then(|m| m.modified()).ok())
+        .max()
+        .unwrap_or(SystemTime::UNIX_EPOCH);
+
+    (files.iter().map(|(_, size)| size).sum(), last_used)
+}
+
+// [FUNC] Delete least recently used mod folders until the cache fits in max_bytes
+// Mods built into the active profile are never evicted
+pub fn evict_cache_to_limit(max_bytes: u64) -> EvictionSummary {
+    let active = read_profile_mods();
+    let is_active = |name: &str| active.iter().any(|m| m == name || *m == format!("marketplace_{}", name));
+
+    let mut entries: Vec<(PathBuf, u64, SystemTime)> = Vec::new();
+    let mut total: u64 = 0;
+
+    for root in get_cache_roots() {
+        if let Ok(dir) = std::fs::read_dir(&root) {
+            for entry in dir.filter_map(|e| e.ok()) {
+                let path = entry.path();
+                if !path.is_dir() {
+                    continue;
+                }
+                let (size, last_used) = folder_usage(&path);
+                total += size;
+
+                let name = entry.file_name().to_string_lossy().to_string();
+                if !is_active(&name) {
+                    entries.push((path, size, last_used));
+                }
+            }
+        }
+    }
+
+    let mut summary = EvictionSummary {
+        evicted: Vec::new(),
+        freed_bytes: 0,
+        remaining_bytes: total,
+        errors: Vec::new(),
+    };
+
+    // Oldest first
+    entries.sort_by_key(|(_, _, last_used)| *last_used);
+
+    for (path, size, _) in entries {
+        if summary.remaining_bytes <= max_bytes {
+            break;
+        }
+        match std::fs::remove_dir_all(&path) {
+            Ok(()) => {
+                summary.evicted.push(path.to_string_lossy().to_string());
+                summary.freed_bytes += size;
+                summary.remaining_bytes -= size;
+            }
+            Err(e) => summary.errors.push(format!("{}: {}", path.to_string_lossy(), e)),
+        }
+    }
+
+    println!("[MOD-CACHE-LRU] Evicted {} folders, freed {} MB, {} MB remaining",
+             summary.evicted.len(), summary.freed_bytes / 1024 / 1024, summary.remaining_bytes / 1024 / 1024);
+
+    summary
+}
+
+// [FUNC] Start the cleanup timer from persisted settings - replaces any running timer
+pub fn start_auto_cache_cleanup(app: AppHandle) {
+    let generation = AUTO_CLEANUP_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+
+    let config = match load_settings().auto_cache_cleanup {
+        Some(config) if config.max_bytes > 0 && config.interval_hours > 0 => config,
+        _ => return,
+    };
+
+    println!("[MOD-CACHE-LRU] Auto cleanup every {}h, limit {} MB",
+             config.interval_hours, config.max_bytes / 1024 / 1024);
+
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(std::time::Duration::from_secs(config.interval_hours * 3600)).await;
+
+            if AUTO_CLEANUP_GENERATION.load(Ordering::SeqCst) != generation || crate::mod_manager::is_shutting_down() {
+                println!("[MOD-CACHE-LRU] Auto cleanup task stopped");
+                break;
+            }
+
+            let max_bytes = config.max_bytes;
+            let summary = match tauri::async_runtime::spawn_blocking(move || evict_cache_to_limit(max_bytes)).await {
+                Ok(summary) => summary,
+                Err(e) => {
+                    println!("[MOD-CACHE-LRU] Auto cleanup failed: {}", e);
+                    continue;
+                }
+            };
+
+            if !summary.evicted.is_empty() || !summary.errors.is_empty() {
+                let _ = app.emit("cache-cleanup", summary);
+            }
+        }
+    });
+}
+
+// [COMMAND] Schedule periodic cache cleanup - None/0 for either value disables it
+#[tauri::command]
+pub async fn set_auto_cache_cleanup(app: AppHandle, max_bytes: Option<u64>, interval_hours: Option<u64>) -> Result<bool, String> {
+    let config = match (max_bytes, interval_hours) {
+        (Some(max_bytes), Some(interval_hours)) if max_bytes > 0 && interval_hours > 0 => {
+            Some(AutoCacheCleanup { max_bytes, interval_hours })
+        }
+        _ => None,
+    };
+    let enabled = config.is_some();
+
+    update_settings(|s| s.auto_cache_cleanup = config)?;
+    start_auto_cache_cleanup(app);
+
+    if !enabled {
+        println!("[MOD-CACHE-LRU] Auto cleanup disabled");
+    }
+    Ok(enabled)
+}
+
+// [FUNC] Scan the mods directory for .zip/.fantome files whose mod folder is missing or has no WAD
+fn scan_orphaned_archives() -> Vec<OrphanInfo> {
+    let mods_dir = get_mods_directory();
+    let mut orphans: Vec<OrphanInfo> = Vec::new();
+
+    if let Ok(entries) = std::fs::read_dir(&mods_dir) {
+        for entry in entries.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            if !path.is_file() {
+                continue;
+            }
+
+            let name = entry.file_name().to_string_lossy().to_string();
+            let lower = name.to_lowercase();
+            let stem = match lower.strip_suffix(".zip").or_else(|| lower.strip_suffix(".fantome")) {
+                Some(stem) => &name[..stem.len()],
+                None => continue,
+            };
+
+            // Archives kept next to a valid extracted folder are not orphans
+            if mods_dir.join(stem).join("WAD").is_dir() {
+                continue;
+            }
+
+            let age = entry
+                .metadata()
+                .and_then(|m| m.modified())
+                .ok()
+                .and_then(|t| t.elapsed().ok())
+                .map(|d| d.as_secs())
+                .unwrap_or(u64::MAX);
+            if age < ORPHAN_MIN_AGE_SECS {
+                continue;
+            }
+
+            orphans.push(OrphanInfo {
+                size: entry.metadata().map(|m| m.len()).unwrap_or(0),
+                path: path.to_string_lossy().to_string(),
+                name,
+            });
+        }
+    }
+
+    orphans.sort_by(|a, b| a.name.cmp(&b.name));
+    orphans
+}
+
+// [COMMAND] List download archives with no matching extracted mod folder
+#[tauri::command]
+pub async fn find_orphaned_archives() -> Vec<OrphanInfo> {
+    let orphans = scan_orphaned_archives();
+    println!("[MOD-CACHE] Found {} orphaned archives", orphans.len());
+    orphans
+}
+
+// [COMMAND] Delete orphaned download archives - reports bytes reclaimed
+#[tauri::command]
+pub async fn clean_orphaned_archives() -> OrphanCleanupResult {
+    let mut result = OrphanCleanupResult {
+        deleted: 0,
+        reclaimed_bytes: 0,
+        errors: Vec::new(),
+    };
+
+    for orphan in scan_orphaned_archives() {
+        match std::fs::remove_file(&orphan.path) {
+            Ok(()) => {
+                result.deleted += 1;
+                result.reclaimed_bytes += orphan.size;
+            }
+            Err(e) => result.errors.push(format!("{}: {}", orphan.name, e)),
+        }
+    }
+
+    println!("[MOD-CACHE] Removed {} orphaned archives, reclaimed {} MB, {} errors",
+             result.deleted, result.reclaimed_bytes / 1024 / 1024, result.errors.len());
+
+    result
+}
+
+// [FUNC] Byte-for-byte comparison - guards hardlinking against hash collisions
+fn files_identical(a: &Path, b: &Path) -> bool {
+    let (mut fa, mut fb) = match (File::open(a), File::open(b)) {
+        (Ok(fa), Ok(fb)) => (fa, fb),
+        _ => return false,
+    };
+
+    let mut buf_a = vec![0u8; HASH_CHUNK_SIZE];
+    let mut buf_b = vec![0u8; HASH_CHUNK_SIZE];
+
+    loop {
+        let read_a = match fa.read(&mut buf_a) {
+            Ok(n) => n,
+            Err(_) => return false,
+        };
+        if read_a == 0 {
+            // Make sure b is exhausted too
+            return matches!(fb.read(&mut buf_b), Ok(0));
+        }
+        if fb.read_exact(&mut buf_b[..read_a]).is_err() {
+            return false;
+        }
+        if buf_a[..read_a] != buf_b[..read_a] {
+            return false;
+        }
+    }
+}
+
+// [FUNC] Scan cache roots and group identical files
+fn scan_duplicates() -> Vec<DuplicateGroup> {
+    let mut files: Vec<(PathBuf, u64)> = Vec::new();
+    for root in get_cache_roots() {
+        collect_files(&root, &mut files);
+    }
+
+    // [HARDLINKS] Files already linked together by an earlier dedupe are not duplicates
+    let files = without_hardlinks(files);
+
+    // [SIZE-GROUP] Only files with matching sizes can be identical
+    let mut by_size: HashMap<u64, Vec<PathBuf>> = HashMap::new();
+    for (path, size) in files {
+        if size >= MIN_DEDUP_FILE_SIZE {
+            by_size.entry(size).or_default().push(path);
+        }
+    }
+
+    let mut groups: Vec<DuplicateGroup> = Vec::new();
+
+    for (size, paths) in by_size {
+        if paths.len() < 2 {
+            continue;
+        }
+
+        let mut by_hash: HashMap<String, Vec<PathBuf>> = HashMap::new();
+        for path in paths {
+            match hash_file(&path) {
+                Ok(hash) => by_hash.entry(hash).or_default().push(path),
+                Err(e) => println!("[MOD-DEDUP] WARN: Failed to hash {:?}: {}", path, e),
+            }
+        }
+
+        for (hash, paths) in by_hash {
+            if paths.len() < 2 {
+                continue;
+            }
+            groups.push(DuplicateGroup {
+                hash,
+                size,
+                reclaimable_bytes: size * (paths.len() as u64 - 1),
+                files: paths.iter().map(|p| p.to_string_lossy().to_string()).collect(),
+            });
+        }
+    }
+
+    groups.sort_by_key(|g| std::cmp::Reverse(g.reclaimable_bytes));
+    groups
+}
+
+// [FUNC] Search cached mod folders by folder name, champion name or marketplace title
+fn scan_cache_matches(query: &str) -> Vec<CacheSearchResult> {
+    let query = query.trim().to_lowercase();
+    let champions = crate::champion_data::load_champion_data();
+    let catalog: HashMap<String, String> = crate::marketplace::get_cached_mods()
+        .into_iter()
+        .map(|m| (m.id, m.name))
+        .collect();
+
+    let roots = [
+        ("mods", get_mods_directory()),
+        ("installed", get_overlay_directory().join("installed")),
+        ("marketplace", get_marketplace_cache_dir()),
+    ];
+
+    let mut results: Vec<CacheSearchResult> = Vec::new();
+
+    for (location, root) in roots {
+        let entries = match std::fs::read_dir(&root) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+
+        for entry in entries.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            let name = entry.file_name().to_string_lossy().to_string();
+            if !path.is_dir() || name.starts_with("temp_") {
+                continue;
+            }
+
+            // Skin folders start with "{champion_id}_", marketplace folders carry the mod id
+            let champion = name
+                .split('_')
+                .next()
+                .and_then(|prefix| prefix.parse::<i32>().ok())
+                .and_then(|id| champions.as_ref().and_then(|data| data.champions.get(&id)))
+                .map(|c| c.name.clone());
+            let mod_id = if location == "marketplace" { Some(name.as_str()) } else { name.strip_prefix("marketplace_") };
+            let title = mod_id.and_then(|id| catalog.get(id)).cloned();
+
+            let matched = query.is_empty()
+                || name.to_lowercase().contains(&query)
+                || champion.as_ref().map(|c| c.to_lowercase().contains(&query)).unwrap_or(false)
+                || title.as_ref().map(|t| t.to_lowercase().contains(&query)).unwrap_or(false);
+            if !matched {
+                continue;
+            }
+
+            let (size, _) = folder_usage(&path);
+            results.push(CacheSearchResult {
+                name,
+                location: location.to_string(),
+                path: path.to_string_lossy().to_string(),
+                size,
+                champion,
+                title,
+            });
+        }
+    }
+
+    results.sort_by_key(|r| r.name.to_lowercase());
+    results
+}
+
+// [COMMAND] Case-insensitive local search over downloaded, installed and marketplace caches
+#[tauri::command]
+pub async fn search_cache(query: String) -> Vec<CacheSearchResult> {
+    let needle = query.clone();
+    let results = tauri::async_runtime::spawn_blocking(move || scan_cache_matches(&needle))
+        .await
+        .unwrap_or_default();
+
+    println!("[MOD-CACHE-SEARCH] '{}': {} matches", query, results.len());
+    results
+}
+
+// [FUNC] Check an extracted skin folder - META/info.json plus WAD files with a valid header
+fn verify_skin_folder(dir: &Path) -> (CacheIntegrity, Option<String>) {
+    if !dir.join("META").join("info.json").is_file() {
+        return (CacheIntegrity::Incomplete, Some("META/info.json missing".to_string()));
+    }
+
+    let wads: Vec<PathBuf> = std::fs::read_dir(dir.join("WAD"))
+        .map(|entries| entries.filter_map(|e| e.ok()).map(|e| e.path()).collect())
+        .unwrap_or_default();
+    if !wads.iter().any(|p| p.to_string_lossy().ends_with(".wad.client")) {
+        return (CacheIntegrity::Incomplete, Some("No WAD files".to_string()));
+    }
+
+    // Unpacked WADs are folders - only packed files carry the "RW" magic
+    for wad in wads.iter().filter(|p| p.is_file()) {
+        let mut magic = [0u8; 2];
+        let valid = File::open(wad).and_then(|mut f| f.read_exact(&mut magic)).is_ok() && &magic == b"RW";
+        if !valid {
+            let name = wad.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+            return (CacheIntegrity::Corrupt, Some(format!("Invalid WAD header: {}", name)));
+        }
+    }
+
+    (CacheIntegrity::Ok, None)
+}
+
+// [FUNC] Check a cached marketplace download - readable archive matching the catalog size and hash
+fn verify_marketplace_folder(
+    dir: &Path,
+    expected_size: Option<u64>,
+    expected_sha256: Option<&str>,
+) -> (CacheIntegrity, Option<String>) {
+    let mod_file = dir.join("mod.fantome");
+    let size = match std::fs::metadata(&mod_file) {
+        Ok(meta) => meta.len(),
+        Err(_) => return (CacheIntegrity::Incomplete, Some("mod.fantome missing".to_string())),
+    };
+
+    if !crate::mod_archive::archive_is_valid(&mod_file) {
+        return (CacheIntegrity::Corrupt, Some(crate::mod_archive::CORRUPT_ARCHIVE.to_string()));
+    }
+
+    if let Some(expected) = expected_size {
+        if expected > 0 && expected != size {
+            return (CacheIntegrity::Corrupt, Some(format!("Size {} does not match catalog size {}", size, expected)));
+        }
+    }
+
+    // Older catalog entries carry no hash - those are only checked by size
+    if let Some(expected) = expected_sha256 {
+        match hash_file(&mod_file) {
+            Ok(actual) if !actual.eq_ignore_ascii_case(expected.trim()) => {
+                return (CacheIntegrity::Corrupt, Some(format!("SHA-256 {} does not match catalog hash {}", actual, expected)));
+            }
+            Ok(_) => {}
+            Err(e) => return (CacheIntegrity::Corrupt, Some(format!("Failed to hash mod.fantome: {}", e))),
+        }
+    }
+
+    (CacheIntegrity::Ok, None)
+}
+
+// [FUNC] Rebuild the download request from a skin folder name
+// Accepts "{champion}_{skin}", "{champion}_{skin}_chroma_{id}" and "{champion}_{skin}_form_{id}"
+fn skin_request_from_folder(name: &str) -> Option<SkinDownloadRequest> {
+    let parts: Vec<&str> = name.split('_').collect();
+    let champion_id = parts.first()?.parse().ok()?;
+    let skin_id = parts.get(1)?.parse().ok()?;
+
+    let (chroma_id, form_id) = match parts.len() {
+        2 => (None, None),
+        4 if parts[2] == "chroma" => (Some(parts[3].parse().ok()?), None),
+        4 if parts[2] == "form" => (None, Some(parts[3].parse().ok()?)),
+        _ => return None,
+    };
+
+    Some(SkinDownloadRequest { champion_id, skin_id, chroma_id, form_id, expected_sha256: None })
+}
+
+// [FUNC] Verify every cached skin and marketplace download
+fn scan_cache_integrity() -> Vec<CacheVerifyResult> {
+    // Size check follows the strict_size_verification setting like downloads do - hashes are always checked
+    let strict_size = load_settings().strict_size_verification;
+    let catalog: HashMap<String, (u64, Option<String>)> = crate::marketplace::get_cached_mods()
+        .into_iter()
+        .map(|m| (m.id, (m.file_size, m.sha256.filter(|hash| !hash.trim().is_empty()))))
+        .collect();
+
+    let roots = [("mods", get_mods_directory()), ("marketplace", get_marketplace_cache_dir())];
+    let mut results = Vec::new();
+
+    for (location, root) in roots {
+        let entries = match std::fs::read_dir(&root) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+
+        for entry in entries.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            let name = entry.file_name().to_string_lossy().to_string();
+            if !path.is_dir() || name.starts_with("temp_") {
+                continue;
+            }
+
+            let (status, detail) = if location == "mods" {
+                verify_skin_folder(&path)
+            } else {
+                let entry = catalog.get(&name);
+                verify_marketplace_folder(
+                    &path,
+                    entry.filter(|_| strict_size).map(|(size, _)| *size),
+                    entry.and_then(|(_, hash)| hash.as_deref()),
+                )
+            };
+
+            results.push(CacheVerifyResult {
+                name,
+                location: location.to_string(),
+                path: path.to_string_lossy().to_string(),
+                status,
+                detail,
+                redownloaded: false,
+            });
+        }
+    }
+
+    results.sort_by(|a, b| a.name.cmp(&b.name));
+    results
+}
+
+// [COMMAND] Check cached mods for corrupt or incomplete content
+// With redownload set, broken entries with a known download source are deleted and fetched again
+// Folders without a source (custom mods, ids missing from the catalog) are reported but kept
+#[tauri::command]
+pub async fn verify_cache_integrity(app: AppHandle, redownload: Option<bool>) -> Vec<CacheVerifyResult> {
+    let mut results = tauri::async_runtime::spawn_blocking(scan_cache_integrity)
+        .await
+        .unwrap_or_default();
+
+    let broken = results.iter().filter(|r| r.status != CacheIntegrity::Ok).count();
+    println!("[MOD-CACHE-VERIFY] Checked {} cached mods, {} broken", results.len(), broken);
+
+    if !redownload.unwrap_or(false) || broken == 0 {
+        return results;
+    }
+
+    let catalog: HashMap<String, crate::marketplace::ModDetail> = crate::marketplace::get_cached_mods()
+        .into_iter()
+        .map(|m| (m.id.clone(), m))
+        .collect();
+
+    for result in results.iter_mut().filter(|r| r.status != CacheIntegrity::Ok) {
+        // Resolve the source before deleting anything - a folder that can't be fetched again is kept
+        let skin_request = if result.location == "mods" { skin_request_from_folder(&result.name) } else { None };
+        let detail = if result.location == "mods" { None } else { catalog.get(&result.name) };
+        if skin_request.is_none() && detail.is_none() {
+            println!("[MOD-CACHE-VERIFY] No download source for {} - keeping it", result.name);
+            continue;
+        }
+
+        // Both download paths treat an existing folder as a cache hit - remove it first
+        if let Err(e) = std::fs::remove_dir_all(&result.path) {
+            println!("[MOD-CACHE-VERIFY] Failed to remove {}: {}", result.name, e);
+            continue;
+        }
+
+        result.redownloaded = match (skin_request, detail) {
+            (Some(request), _) => crate::mod_manager::download_skin(app.clone(), request).await.success,
+            (None, Some(detail)) => crate::marketplace::download_marketplace_mod(
+                app.clone(),
+                detail.id.clone(),
+                detail.download_url.clone(),
+                detail.name.clone(),
+            ).await.success,
+            (None, None) => false,
+        };
+
+        println!("[MOD-CACHE-VERIFY] Redownload {}: {}", result.name, result.redownloaded);
+    }
+
+    results
+}
+
+// [FUNC] Group cached skin folders ("{champion_id}_...") by champion
+fn scan_cached_champions() -> Vec<ChampionCacheSummary> {
+    let champions = crate::champion_data::load_champion_data();
+    let mut groups: HashMap<i32, (usize, u64)> = HashMap::new();
+
+    if let Ok(entries) = std::fs::read_dir(get_mods_directory()) {
+        for entry in entries.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            if !path.is_dir() {
+                continue;
+            }
+
+            let name = entry.file_name().to_string_lossy().to_string();
+            let champion_id = match name.split('_').next().and_then(|prefix| prefix.parse::<i32>().ok()) {
+                Some(id) => id,
+                None => continue,
+            };
+
+            let (size, _) = folder_usage(&path);
+            let group = groups.entry(champion_id).or_insert((0, 0));
+            group.0 += 1;
+            group.1 += size;
+        }
+    }
+
+    let mut summaries: Vec<ChampionCacheSummary> = groups
+        .into_iter()
+        .map(|(champion_id, (skin_count, total_size))| ChampionCacheSummary {
+            champion_id,
+            name: champions.as_ref().and_then(|data| data.champions.get(&champion_id)).map(|c| c.name.clone()),
+            skin_count,
+            total_size,
+        })
+        .collect();
+
+    // Named champions alphabetically, unresolved ids last
+    summaries.sort_by(|a, b| match (&a.name, &b.name) {
+        (Some(x), Some(y)) => x.cmp(y),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => a.champion_id.cmp(&b.champion_id),
+    });
+    summaries
+}
+
+// [COMMAND] Cached skins per champion with counts and sizes
+#[tauri::command]
+pub async fn list_cached_champions() -> Vec<ChampionCacheSummary> {
+    let summaries = tauri::async_runtime::spawn_blocking(scan_cached_champions)
+        .await
+        .unwrap_or_default();
+
+    println!("[MOD-CACHE] Cached skins span {} champions", summaries.len());
+    summaries
+}
+
+// [FUNC] Describe every folder under overlay/installed
+fn scan_installed_mods() -> Vec<InstalledMod> {
+    let active = read_profile_mods();
+    let mut mods: Vec<InstalledMod> = Vec::new();
+
+    if let Ok(entries) = std::fs::read_dir(get_overlay_directory().join("installed")) {
+        for entry in entries.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            if !path.is_dir() {
+                continue;
+            }
+
+            let folder = entry.file_name().to_string_lossy().to_string();
+            let info = crate::mod_archive::load_mod_info(&path).unwrap_or_default();
+            let (size, _) = folder_usage(&path);
+
+            mods.push(InstalledMod {
+                is_marketplace: folder.starts_with("marketplace_"),
+                is_active: active.contains(&folder),
+                path: path.to_string_lossy().to_string(),
+                display_name: info.name,
+                author: info.author,
+                version: info.version,
+                size,
+                folder,
+            });
+        }
+    }
+
+    mods.sort_by_key(|m| m.display_name.clone().unwrap_or_else(|| m.folder.clone()).to_lowercase());
+    mods
+}
+
+// [COMMAND] List imported mods with friendly names for the cache manager
+#[tauri::command]
+pub async fn list_installed_mods() -> Vec<InstalledMod> {
+    let mods = tauri::async_runtime::spawn_blocking(scan_installed_mods)
+        .await
+        .unwrap_or_default();
+
+    println!("[MOD-CACHE] Listed {} installed mods", mods.len());
+    mods
+}
+
+// [COMMAND] Find identical files cached under different mod folders
+#[tauri::command]
+pub async fn find_duplicate_cache_files() -> Vec<DuplicateGroup> {
+    println!("[MOD-DEDUP] Scanning cache for duplicate files...");
+
+    let groups = tauri::async_runtime::spawn_blocking(scan_duplicates)
+        .await
+        .unwrap_or_default();
+
+    let reclaimable: u64 = groups.iter().map(|g| g.reclaimable_bytes).sum();
+    println!("[MOD-DEDUP] Found {} duplicate groups, {} MB reclaimable",
+             groups.len(), reclaimable / 1024 / 1024);
+
+    groups
+}
+
+// [COMMAND] Replace duplicate cached files with hardlinks to a single copy
+#[tauri::command]
+pub async fn dedupe_cache_files() -> DedupeResult {
+    println!("[MOD-DEDUP] Deduplicating cache with hardlinks...");
+
+    tauri::async_runtime::spawn_blocking(|| {
+        let mut result = DedupeResult {
+            linked_files: 0,
+            reclaimed_bytes: 0,
+            errors: Vec::new(),
+        };
+
+        for group in scan_duplicates() {
+            let keep = PathBuf::from(&group.files[0]);
+
+            for other in group.files.iter().skip(1) {
+                let other = PathBuf::from(other);
+
+                if !files_identical(&keep, &other) {
+                    println!("[MOD-DEDUP] WARN: Hash match but content differs: {:?}", other);
+                    continue;
+                }
+
+                // Link to a temp name first, then swap in - never leaves the file missing
+                let temp_link = other.with_extension("dedup_tmp");
+                let _ = std::fs::remove_file(&temp_link);
+
+                let linked = std::fs::hard_link(&keep, &temp_link)
+                    .and_then(|_| std::fs::rename(&temp_link, &other));
+
+                match linked {
+                    Ok(()) => {
+                        result.linked_files += 1;
+                        result.reclaimed_bytes += group.size;
+                    }
+                    Err(e) => {
+                        let _ = std::fs::remove_file(&temp_link);
+                        result.errors.push(format!("{}: {}", other.to_string_lossy(), e));
+                    }
+                }
+            }
+        }
+
+        println!("[MOD-DEDUP] Linked {} files, reclaimed {} MB, {} errors",
+                 result.linked_files, result.reclaimed_bytes / 1024 / 1024, result.errors.len());
+
+        result
+    })
+    .await
+    .unwrap_or(DedupeResult {
+        linked_files: 0,
+        reclaimed_bytes: 0,
+        errors: vec!["Deduplication task failed".to_string()],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hardlinked_files_are_counted_once() {
+        let dir = std::env::temp_dir().join(format!("wildflover_links_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let contents = vec![7u8; MIN_DEDUP_FILE_SIZE as usize];
+        std::fs::write(dir.join("a.wad"), &contents).unwrap();
+        std::fs::write(dir.join("copy.wad"), &contents).unwrap();
+        std::fs::hard_link(dir.join("a.wad"), dir.join("link.wad")).unwrap();
+
+        let mut files = Vec::new();
+        collect_files(&dir, &mut files);
+        let distinct = without_hardlinks(files);
+        let (size, _) = folder_usage(&dir);
+        std::fs::remove_dir_all(&dir).ok();
+
+        // a.wad and link.wad are one file on disk, copy.wad is a real duplicate
+        assert_eq!(distinct.len(), 2);
+        assert_eq!(size, MIN_DEDUP_FILE_SIZE * 2);
+    }
+}
@@ -5,16 +5,22 @@
 //!              - ZIP extraction for mod files
 //!              - mod-tools.exe integration for overlay creation
 //!              - Persistent overlay process management (bocchi-style)
+//!              - Watchdog restarts runoverlay if it crashes mid-game
+//!              - overlay-status-changed event on every overlay.status transition
 //! Language: Rust
 
 use serde::{Deserialize, Serialize};
 use std::fs::File;
 use std::io::{Read, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Child, Stdio};
-use std::sync::Mutex;
+use std::sync::{Mutex, OnceLock};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use tokio::fs;
+use tokio_util::sync::CancellationToken;
 use reqwest::Client;
+use tauri::{AppHandle, Emitter};
 use zip::ZipArchive;
 
 // [WINDOWS] Import for hiding console window
@@ -28,6 +34,58 @@ const CREATE_NO_WINDOW: u32 = 0x08000000;
 // [STATE] Global overlay process holder - keeps process alive
 lazy_static::lazy_static! {
     static ref OVERLAY_PROCESS: Mutex<Option<Child>> = Mutex::new(None);
+    static ref OVERLAY_LAUNCH: Mutex<Option<OverlayLaunch>> = Mutex::new(None);
+    static ref INJECTED_MODS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+    static ref ACTIVE_DOWNLOADS: Mutex<HashMap<String, (u64, CancellationToken)>> = Mutex::new(HashMap::new());
+}
+
+// [STATE] Registration id for ACTIVE_DOWNLOADS - a newer download of the same folder replaces the entry
+static DOWNLOAD_SEQ: AtomicU64 = AtomicU64::new(0);
+
+// [STATE] Overlay paused flag - profile kept, runoverlay stopped
+static OVERLAY_PAUSED: AtomicBool = AtomicBool::new(false);
+
+// [STATE] Set once the app is exiting - background loops stop on their next tick
+static SHUTTING_DOWN: AtomicBool = AtomicBool::new(false);
+
+// [STATE] Bumped on every overlay launch and shutdown - a watchdog exits once its generation is stale
+static OVERLAY_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+// [STATE] App handle for events raised outside of commands (overlay watchdog)
+static APP_HANDLE: OnceLock<AppHandle> = OnceLock::new();
+
+// [CONST] Overlay watchdog poll interval and restart budget per user-initiated launch
+const OVERLAY_WATCHDOG_INTERVAL_SECS: u64 = 3;
+const MAX_OVERLAY_RESTARTS: u32 = 3;
+
+// [CONST] How long runoverlay gets to clean up after the stdin signal before it is killed
+const OVERLAY_SHUTDOWN_TIMEOUT_MS: u64 = 3000;
+const OVERLAY_SHUTDOWN_POLL_MS: u64 = 100;
+
+// [STRUCT] Arguments of the last runoverlay launch - used to resume
+#[derive(Clone)]
+struct OverlayLaunch {
+    mod_tools: PathBuf,
+    overlay_dir: PathBuf,
+    profile_dir: PathBuf,
+    game_path: String,
+    mod_count: usize,
+}
+
+// [STRUCT] Overlay restart notice - payload of the overlay-restarted event
+#[derive(Serialize, Clone)]
+pub struct OverlayRestarted {
+    pub attempt: u32,
+    pub max_attempts: u32,
+    pub exit_code: Option<i32>,
+}
+
+// [STRUCT] Overlay state transition - payload of the overlay-status-changed event
+#[derive(Serialize, Clone)]
+pub struct OverlayStatusChanged {
+    pub status: String,
+    pub pid: Option<u32>,
+    pub mod_count: usize,
 }
 
 // [STRUCT] Skin download request
@@ -37,6 +95,17 @@ pub struct SkinDownloadRequest {
     pub skin_id: i32,
     pub chroma_id: Option<i32>,
     pub form_id: Option<i32>,
+    #[serde(default)]
+    pub expected_sha256: Option<String>,
+}
+
+// [STRUCT] Skin download progress - payload of the skin-download-progress event
+#[derive(Serialize, Clone)]
+pub struct SkinDownloadProgress {
+    pub champion_id: i32,
+    pub skin_id: i32,
+    pub downloaded: u64,
+    pub total: Option<u64>,
 }
 
 // [STRUCT] Download result
@@ -45,19 +114,71 @@ pub struct DownloadResult {
     pub success: bool,
     pub path: Option<String>,
     pub error: Option<String>,
+    pub extract_stats: Option<ExtractStats>,
+}
+
+// [STRUCT] What extract_zip wrote and what the extraction filters left out
+#[derive(Serialize, Default)]
+pub struct ExtractStats {
+    pub extracted: usize,
+    pub skipped: usize,
+    pub skipped_names: Vec<String>,
 }
 
 // [STRUCT] Activation result
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct ActivationResult {
     pub success: bool,
     pub message: String,
     pub error: Option<String>,
     pub vanguard_blocked: bool,
+    #[serde(default)]
+    pub failed_mods: Vec<FailedMod>,
+    #[serde(default)]
+    pub timings: Option<ActivationTimings>,
+    #[serde(default)]
+    pub error_code: Option<String>,
+}
+
+// [STRUCT] Wall-clock time per activation phase in milliseconds - None if the phase never ran
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct ActivationTimings {
+    pub import_ms: Option<u64>,
+    pub mkoverlay_ms: Option<u64>,
+    pub overlay_start_ms: Option<u64>,
+    pub total_ms: u64,
+}
+
+// [STRUCT] Mod skipped during activation - path kept so it can be retried
+#[derive(Serialize, Deserialize, Clone)]
+pub struct FailedMod {
+    pub name: String,
+    pub path: String,
+    pub reason: String,
+}
+
+// [STRUCT] Persisted outcome of the most recent activation
+#[derive(Serialize, Deserialize, Clone)]
+pub struct LastActivation {
+    pub result: ActivationResult,
+    pub mods: Vec<String>,
+    pub game_path: String,
+    pub timestamp: u64,
+}
+
+// [STRUCT] Skin repository reachability status
+#[derive(Serialize)]
+pub struct RepoStatus {
+    pub reachable: bool,
+    pub repo_available: bool,
+    pub rate_limited: bool,
+    pub status_code: Option<u16>,
+    pub latency_ms: Option<u64>,
+    pub error: Option<String>,
 }
 
 // [STRUCT] Mod item for activation
-#[derive(Deserialize)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct ModItem {
     pub name: String,
     pub path: String,
@@ -65,22 +186,156 @@ pub struct ModItem {
     pub _is_custom: bool,  // Prefixed with underscore - reserved for future use
 }
 
-// [CONST] GitHub raw content URL for skins
-const GITHUB_BASE_URL: &str = "https://raw.githubusercontent.com/Alban1911/LeagueSkins/main/skins";
+// [STRUCT] User-chosen winner for a conflicting mod pair
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConflictResolution {
+    pub mod_a: String,
+    pub mod_b: String,
+    pub winner: String,
+}
+
+// [FUNC] GitHub raw content URL for skins - built from the configured skin repo
+fn skin_base_url() -> String {
+    let config = crate::settings::load_settings().skin_repo;
+    let base = format!("https://raw.githubusercontent.com/{}/{}/{}", config.owner, config.repo, config.branch);
+    if config.subpath.is_empty() {
+        base
+    } else {
+        format!("{}/{}", base, config.subpath)
+    }
+}
+
+// [FUNC] Get skin mirror list location - next to game_path.txt
+fn get_skin_mirrors_config() -> PathBuf {
+    let app_data = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
+    app_data.join("Wildflover").join("skin_mirrors.json")
+}
+
+// [FUNC] Configured skin mirrors in priority order - empty when none are set
+fn load_skin_mirrors() -> Vec<String> {
+    std::fs::read_to_string(get_skin_mirrors_config())
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+// [FUNC] Base URLs download_skin tries in order - the skin repo when no mirrors are configured
+fn skin_base_urls() -> Vec<String> {
+    let mirrors = load_skin_mirrors();
+    if mirrors.is_empty() {
+        vec![skin_base_url()]
+    } else {
+        mirrors
+    }
+}
+
+// [CONST] Known skin file probed by check_skin_repo - the Sahn-Uzal special path download_skin relies on
+const SKIN_REPO_PROBE_PATH: &str = "82/82054/82999/82999.fantome";
 
 // [CONST] DLL configuration - uses local cslol-dll.dll from managers folder
 const DLL_FILE_NAME: &str = "cslol-dll.dll";
 
 
 
+// [CONST] Skin download failure codes - the UI maps them to "try again later" vs "not available"
+const SKIN_NOT_AVAILABLE: &str = "SKIN_NOT_AVAILABLE";
+const SKIN_NETWORK_ERROR: &str = "NETWORK_ERROR";
+const SKIN_RATE_LIMITED: &str = "RATE_LIMITED";
+const SKIN_CHECKSUM_FAILED: &str = "CHECKSUM_FAILED";
+const SKIN_DOWNLOAD_CANCELLED: &str = "CANCELLED";
+
+// [CONST] Upper bound for download_skins_batch concurrency - GitHub throttles bursts
+const MAX_BATCH_CONCURRENCY: usize = 4;
+
+// [CONST] Overlay failure codes parsed from mod-tools output - set as ActivationResult.error_code
+const OVERLAY_VANGUARD_BLOCKED: &str = "VANGUARD_BLOCKED";
+const OVERLAY_GAME_PATH_INVALID: &str = "GAME_PATH_INVALID";
+const OVERLAY_PROFILE_INVALID: &str = "PROFILE_INVALID";
+const OVERLAY_HOOK_FAILED: &str = "HOOK_FAILED";
+
+// [CONST] Specific cslol error phrases per failure code (lowercase)
+// mod-tools echoes profile/config/DLL paths in ordinary output, so bare words like "profile" or ".dll" prove nothing
+const OVERLAY_ERROR_PATTERNS: &[(&str, &[&str])] = &[
+    (OVERLAY_VANGUARD_BLOCKED, &["c0000229", "ah_result"]),
+    (OVERLAY_HOOK_FAILED, &["failed to hook", "failed to inject", "failed to load dll", "hook failed"]),
+    (OVERLAY_GAME_PATH_INVALID, &["game path does not exist", "failed to find game", "league of legends.exe not found", "not a valid game path"]),
+    (OVERLAY_PROFILE_INVALID, &["failed to parse config", "failed to read profile", "invalid profile", "profile does not exist"]),
+];
+
+// [FUNC] Map known mod-tools error output to a failure code - shared by mkoverlay and runoverlay
+// Unrecognised output returns None so the raw exit code is shown instead of a wrong fix
+fn classify_overlay_error(output: &str) -> Option<&'static str> {
+    let lower = output.to_lowercase();
+    
+    OVERLAY_ERROR_PATTERNS
+        .iter()
+        .find(|(_, patterns)| patterns.iter().any(|p| lower.contains(p)))
+        .map(|(code, _)| *code)
+}
+
+// [FUNC] Whether Riot Vanguard is running - vgc/vgk services or the vgtray.exe tray process
+fn vanguard_running() -> bool {
+    #[cfg(windows)]
+    {
+        let mut tray = Command::new("tasklist");
+        tray.args(["/FI", "IMAGENAME eq vgtray.exe", "/NH"]);
+        tray.creation_flags(CREATE_NO_WINDOW);
+        if let Ok(output) = tray.output() {
+            if String::from_utf8_lossy(&output.stdout).to_lowercase().contains("vgtray.exe") {
+                println!("[MOD-VANGUARD] vgtray.exe is running");
+                return true;
+            }
+        }
+        
+        for service in ["vgc", "vgk"] {
+            let mut query = Command::new("sc");
+            query.args(["query", service]);
+            query.creation_flags(CREATE_NO_WINDOW);
+            if let Ok(output) = query.output() {
+                if String::from_utf8_lossy(&output.stdout).contains("RUNNING") {
+                    println!("[MOD-VANGUARD] Service {} is running", service);
+                    return true;
+                }
+            }
+        }
+        
+        false
+    }
+    
+    #[cfg(not(windows))]
+    {
+        false
+    }
+}
+
+// [COMMAND] Check for Vanguard up front so the UI can warn before an activation is attempted
+// Advisory only - Vanguard is normally always running, an actual block shows up as C0000229
+#[tauri::command]
+pub async fn is_vanguard_active() -> bool {
+    vanguard_running()
+}
+
+// [FUNC] Actionable message for an overlay failure code
+fn overlay_error_message(code: &str) -> &'static str {
+    match code {
+        OVERLAY_VANGUARD_BLOCKED => "Vanguard blocked the overlay - restart the client and try again",
+        OVERLAY_HOOK_FAILED => "Overlay could not hook the game - check the DLL is present and not quarantined",
+        OVERLAY_GAME_PATH_INVALID => "Game path rejected by the overlay - reselect the League of Legends Game folder",
+        OVERLAY_PROFILE_INVALID => "Overlay profile is invalid - clear the cache and activate again",
+        OVERLAY_LOCKED => "Overlay files are locked by another process - close mod-tools or your antivirus scan and try again",
+        _ => "Overlay failed",
+    }
+}
+
 // [FUNC] Get app data directory for storing downloaded mods
-fn get_mods_directory() -> PathBuf {
+pub fn get_mods_directory() -> PathBuf {
     let app_data = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
     app_data.join("Wildflover").join("mods")
 }
 
 // [FUNC] Get overlay directory
-fn get_overlay_directory() -> PathBuf {
+pub fn get_overlay_directory() -> PathBuf {
     let app_data = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
     app_data.join("Wildflover").join("overlay")
 }
@@ -145,7 +400,7 @@ fn get_managers_directory() -> Option<PathBuf> {
 
 // [FUNC] Extract ZIP file to target directory
 // Filters out locale-specific WAD files and problematic assets that can cause game crashes
-fn extract_zip(zip_path: &PathBuf, target_dir: &PathBuf) -> Result<(), String> {
+fn extract_zip(zip_path: &PathBuf, target_dir: &PathBuf) -> Result<ExtractStats, String> {
     let file = File::open(zip_path)
         .map_err(|e| format!("Failed to open ZIP: {}", e))?;
     
@@ -154,14 +409,14 @@ fn extract_zip(zip_path: &PathBuf, target_dir: &PathBuf) -> Result<(), String> {
     
     println!("[MOD-EXTRACT] Extracting {} files from {:?}", archive.len(), zip_path);
     
+    // [FILTER] User-configurable - defaults skip every locale, TFT and crash-prone assets
+    let filters = crate::settings::load_settings().extraction_filters;
+    
     // [FILTER] Locale patterns to skip - these cause game crashes
-    let locale_patterns = [
-        ".tr_TR.", ".en_US.", ".en_GB.", ".de_DE.", ".es_ES.", ".es_MX.",
-        ".fr_FR.", ".it_IT.", ".pl_PL.", ".pt_BR.", ".ro_RO.", ".ru_RU.",
-        ".el_GR.", ".cs_CZ.", ".hu_HU.", ".ja_JP.", ".ko_KR.", ".zh_CN.",
-        ".zh_TW.", ".th_TH.", ".vi_VN.", ".ar_AE.", ".id_ID.", ".ms_MY.",
-        ".ph_PH.", ".sg_SG.", ".tw_TW."
-    ];
+    let locale_patterns: Vec<String> = filters.skip_locales
+        .iter()
+        .map(|locale| format!(".{}.", locale))
+        .collect();
     
     // [FILTER] TFT-related patterns to skip - can cause crashes with regular LoL
     let tft_patterns = [
@@ -178,6 +433,7 @@ fn extract_zip(zip_path: &PathBuf, target_dir: &PathBuf) -> Result<(), String> {
     
     let mut extracted_count = 0;
     let mut skipped_count = 0;
+    let mut skipped_names: Vec<String> = Vec::new();
     
     for i in 0..archive.len() {
         let mut file = archive.by_index(i)
@@ -186,26 +442,29 @@ fn extract_zip(zip_path: &PathBuf, target_dir: &PathBuf) -> Result<(), String> {
         let file_name = file.name().to_string();
         
         // [SKIP] Locale-specific WAD files
-        let is_locale_file = locale_patterns.iter().any(|p| file_name.contains(p));
+        let is_locale_file = locale_patterns.iter().any(|p| file_name.contains(p.as_str()));
         if is_locale_file {
             println!("[MOD-EXTRACT] Skipping locale file: {}", file_name);
             skipped_count += 1;
+            skipped_names.push(file_name.clone());
             continue;
         }
         
         // [SKIP] TFT-related files (crash prevention)
-        let is_tft_file = tft_patterns.iter().any(|p| file_name.contains(p));
+        let is_tft_file = filters.skip_tft && tft_patterns.iter().any(|p| file_name.contains(p));
         if is_tft_file {
             println!("[MOD-EXTRACT] Skipping TFT file: {}", file_name);
             skipped_count += 1;
+            skipped_names.push(file_name.clone());
             continue;
         }
         
         // [SKIP] Known problematic assets (optional - only skip if causing issues)
-        let is_crash_prone = crash_patterns.iter().any(|p| file_name.contains(p));
+        let is_crash_prone = filters.skip_crash_prone && crash_patterns.iter().any(|p| file_name.contains(p));
         if is_crash_prone && file_name.ends_with(".wad.client") {
             println!("[MOD-EXTRACT] Skipping crash-prone file: {}", file_name);
             skipped_count += 1;
+            skipped_names.push(file_name.clone());
             continue;
         }
         
@@ -243,12 +502,147 @@ fn extract_zip(zip_path: &PathBuf, target_dir: &PathBuf) -> Result<(), String> {
     
     println!("[MOD-EXTRACT] Extraction complete: {} extracted, {} files skipped (locale/TFT/crash-prone)", 
              extracted_count, skipped_count);
-    Ok(())
+    Ok(ExtractStats {
+        extracted: extracted_count,
+        skipped: skipped_count,
+        skipped_names,
+    })
+}
+
+// [FUNC] Emit skin-download-progress for a request
+fn emit_skin_progress(app: &AppHandle, request: &SkinDownloadRequest, downloaded: u64, total: Option<u64>) {
+    let _ = app.emit("skin-download-progress", SkinDownloadProgress {
+        champion_id: request.champion_id,
+        skin_id: request.skin_id,
+        downloaded,
+        total,
+    });
+}
+
+// [STRUCT] In-flight skin download registration - unregisters itself when the download ends
+struct ActiveDownload {
+    key: String,
+    id: u64,
+    token: CancellationToken,
+}
+
+impl ActiveDownload {
+    fn register(key: &str) -> Self {
+        let id = DOWNLOAD_SEQ.fetch_add(1, Ordering::SeqCst);
+        let token = CancellationToken::new();
+        if let Ok(mut active) = ACTIVE_DOWNLOADS.lock() {
+            active.insert(key.to_string(), (id, token.clone()));
+        }
+        ActiveDownload { key: key.to_string(), id, token }
+    }
+}
+
+impl Drop for ActiveDownload {
+    fn drop(&mut self) {
+        if let Ok(mut active) = ACTIVE_DOWNLOADS.lock() {
+            if active.get(&self.key).map(|(id, _)| *id == self.id).unwrap_or(false) {
+                active.remove(&self.key);
+            }
+        }
+    }
+}
+
+// [FUNC] Result for a download stopped by cancel_download - partial archive removed
+// Pass None when nothing has been written yet (e.g. cancelled while queued for a permit)
+fn cancelled_download(download_path: Option<&Path>) -> DownloadResult {
+    match download_path {
+        Some(path) => {
+            let _ = std::fs::remove_file(path);
+            println!("[MOD-DOWNLOAD] Download cancelled: {:?}", path);
+        }
+        None => println!("[MOD-DOWNLOAD] Download cancelled before it started"),
+    }
+    DownloadResult {
+        success: false,
+        path: None,
+        error: Some(SKIN_DOWNLOAD_CANCELLED.to_string()),
+        extract_stats: None,
+    }
+}
+
+// [FUNC] Read a skin archive body chunk by chunk, emitting progress from Content-Length
+// The last event always reports downloaded == total so the UI reaches 100% without a length header
+// Returns Ok(None) when the download is cancelled mid-body
+async fn read_skin_body(
+    app: &AppHandle,
+    request: &SkinDownloadRequest,
+    mut response: reqwest::Response,
+    cancel: &CancellationToken,
+) -> Result<Option<Vec<u8>>, reqwest::Error> {
+    let total = response.content_length();
+    let mut bytes: Vec<u8> = Vec::with_capacity(total.unwrap_or(0) as usize);
+    let mut last_emit = std::time::Instant::now();
+    
+    loop {
+        let chunk = tokio::select! {
+            chunk = response.chunk() => chunk?,
+            _ = cancel.cancelled() => return Ok(None),
+        };
+        let chunk = match chunk {
+            Some(chunk) => chunk,
+            None => break,
+        };
+        
+        bytes.extend_from_slice(&chunk);
+        
+        if last_emit.elapsed().as_millis() >= 250 {
+            emit_skin_progress(app, request, bytes.len() as u64, total);
+            last_emit = std::time::Instant::now();
+        }
+    }
+    
+    let downloaded = bytes.len() as u64;
+    emit_skin_progress(app, request, downloaded, Some(total.unwrap_or(downloaded).max(downloaded)));
+    
+    Ok(Some(bytes))
+}
+
+// [FUNC] HTTP client for skin archives - shared across a batch
+fn skin_download_client() -> Client {
+    Client::builder()
+        .timeout(std::time::Duration::from_secs(120))
+        .connect_timeout(std::time::Duration::from_secs(30))
+        .build()
+        .unwrap_or_else(|_| Client::new())
 }
 
 // [COMMAND] Download skin from GitHub - with cache check
 #[tauri::command]
-pub async fn download_skin(request: SkinDownloadRequest) -> DownloadResult {
+pub async fn download_skin(app: AppHandle, request: SkinDownloadRequest) -> DownloadResult {
+    download_skin_with(&app, &skin_download_client(), request).await
+}
+
+// [COMMAND] Download several skins in parallel - results follow the input order
+// Concurrency is capped at MAX_BATCH_CONCURRENCY and still shares the global download budget
+#[tauri::command]
+pub async fn download_skins_batch(app: AppHandle, requests: Vec<SkinDownloadRequest>, concurrency: usize) -> Vec<DownloadResult> {
+    use futures::stream::{self, StreamExt};
+    
+    let concurrency = concurrency.clamp(1, MAX_BATCH_CONCURRENCY);
+    let client = skin_download_client();
+    println!("[MOD-DOWNLOAD] Batch of {} skins, concurrency {}", requests.len(), concurrency);
+    
+    let mut results: Vec<(usize, DownloadResult)> = stream::iter(requests.into_iter().enumerate())
+        .map(|(index, request)| {
+            let app = &app;
+            let client = &client;
+            async move { (index, download_skin_with(app, client, request).await) }
+        })
+        .buffer_unordered(concurrency)
+        .collect()
+        .await;
+    
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+// [FUNC] Download one skin with a caller-provided client
+async fn download_skin_with(app: &AppHandle, client: &Client, request: SkinDownloadRequest) -> DownloadResult {
     println!("[MOD-DOWNLOAD] Starting download for champion {} skin {}", 
              request.champion_id, request.skin_id);
     
@@ -281,6 +675,7 @@ pub async fn download_skin(request: SkinDownloadRequest) -> DownloadResult {
                         success: true,
                         path: Some(mod_folder.to_string_lossy().to_string()),
                         error: None,
+                        extract_stats: None,
                     };
                 }
             }
@@ -294,10 +689,11 @@ pub async fn download_skin(request: SkinDownloadRequest) -> DownloadResult {
     // 
     // [SPECIAL-CASE] Mordekaiser Sahn-Uzal (82054) uses special fantome path
     // URL: /skins/82/82054/82999/82999.fantome
+    let github_base_url = skin_base_url();
     let (primary_url, fallback_url) = if request.champion_id == 82 && request.skin_id == 82054 && request.chroma_id.is_none() && request.form_id.is_none() {
         // [MORDEKAISER-SAHN-UZAL] Special case - use 82999 fantome file
-        let fantome_url = format!("{}/82/82054/82999/82999.fantome", GITHUB_BASE_URL);
-        let zip_url = format!("{}/82/82054/82999/82999.zip", GITHUB_BASE_URL);
+        let fantome_url = format!("{}/82/82054/82999/82999.fantome", github_base_url);
+        let zip_url = format!("{}/82/82054/82999/82999.zip", github_base_url);
         println!("[MOD-DOWNLOAD] Using Mordekaiser Sahn-Uzal special path: {}", fantome_url);
         (fantome_url, zip_url)
     } else if let Some(form_id) = request.form_id {
@@ -310,13 +706,13 @@ pub async fn download_skin(request: SkinDownloadRequest) -> DownloadResult {
         };
         
         let zip_url = format!("{}/{}/{}/{}/{}.zip", 
-                GITHUB_BASE_URL, 
+                github_base_url, 
                 request.champion_id, 
                 request.skin_id,
                 actual_form_id,
                 actual_form_id);
         let fantome_url = format!("{}/{}/{}/{}/{}.fantome", 
-                GITHUB_BASE_URL, 
+                github_base_url, 
                 request.champion_id, 
                 request.skin_id,
                 actual_form_id,
@@ -324,13 +720,13 @@ pub async fn download_skin(request: SkinDownloadRequest) -> DownloadResult {
         (zip_url, fantome_url)
     } else if let Some(chroma_id) = request.chroma_id {
         let zip_url = format!("{}/{}/{}/{}/{}.zip", 
-                GITHUB_BASE_URL, 
+                github_base_url, 
                 request.champion_id, 
                 request.skin_id,
                 chroma_id,
                 chroma_id);
         let fantome_url = format!("{}/{}/{}/{}/{}.fantome", 
-                GITHUB_BASE_URL, 
+                github_base_url, 
                 request.champion_id, 
                 request.skin_id,
                 chroma_id,
@@ -338,12 +734,12 @@ pub async fn download_skin(request: SkinDownloadRequest) -> DownloadResult {
         (zip_url, fantome_url)
     } else {
         let zip_url = format!("{}/{}/{}/{}.zip", 
-                GITHUB_BASE_URL, 
+                github_base_url, 
                 request.champion_id, 
                 request.skin_id,
                 request.skin_id);
         let fantome_url = format!("{}/{}/{}/{}.fantome", 
-                GITHUB_BASE_URL, 
+                github_base_url, 
                 request.champion_id, 
                 request.skin_id,
                 request.skin_id);
@@ -359,27 +755,43 @@ pub async fn download_skin(request: SkinDownloadRequest) -> DownloadResult {
             success: false,
             path: None,
             error: Some(format!("Failed to create mods directory: {}", e)),
+            extract_stats: None,
         };
     }
     
-    // Create HTTP client with timeout
-    let client = Client::builder()
-        .timeout(std::time::Duration::from_secs(120))
-        .connect_timeout(std::time::Duration::from_secs(30))
-        .build()
-        .unwrap_or_else(|_| Client::new());
+    // [CANCEL] Registered before waiting for a permit so queued downloads can be cancelled too
+    let active = ActiveDownload::register(&mod_folder_name);
     
-    // Try primary URL (.zip) first, then fallback (.fantome)
-    let urls_to_try = vec![
-        (primary_url.clone(), "zip"),
-        (fallback_url.clone(), "fantome"),
-    ];
+    // [THROTTLE] Share the global download budget with marketplace/preview fetches
+    let _permit = tokio::select! {
+        permit = crate::download_limiter::acquire_download_permit() => permit,
+        _ = active.token.cancelled() => return cancelled_download(None),
+    };
+    
+    // Try primary URL (.zip) first, then fallback (.fantome) - on every mirror in order
+    let on_mirror = |url: &str, base: &str| format!("{}{}", base, url.strip_prefix(github_base_url.as_str()).unwrap_or(url));
+    let urls_to_try: Vec<(String, &str, String)> = skin_base_urls()
+        .into_iter()
+        .flat_map(|base| vec![
+            (on_mirror(&primary_url, &base), "zip", base.clone()),
+            (on_mirror(&fallback_url, &base), "fantome", base),
+        ])
+        .collect();
+    
+    let mut corrupt_download = false;
+    let mut checksum_failed = false;
+    // [FAILURE-REASON] Responses seen across both URLs - picks the most specific error code
+    let mut rate_limited = false;
+    let mut network_error = false;
     
-    for (url, file_type) in urls_to_try {
+    for (url, file_type, mirror) in urls_to_try {
         println!("[MOD-DOWNLOAD] Trying {} file: {}", file_type, url);
         
         let download_path = mods_dir.join(format!("{}.{}", mod_folder_name, file_type));
         
+        // [PROGRESS] A fallback from .zip to .fantome starts the bar over
+        emit_skin_progress(app, &request, 0, None);
+        
         // Download with retry
         let mut attempts = 0;
         let max_attempts = 2;
@@ -388,11 +800,17 @@ pub async fn download_skin(request: SkinDownloadRequest) -> DownloadResult {
             attempts += 1;
             println!("[MOD-DOWNLOAD] Attempt {}/{} for {}", attempts, max_attempts, file_type);
             
-            match client.get(&url).send().await {
+            let sent = tokio::select! {
+                sent = client.get(&url).send() => sent,
+                _ = active.token.cancelled() => return cancelled_download(Some(&download_path)),
+            };
+            
+            match sent {
                 Ok(response) => {
                     if response.status().is_success() {
-                        match response.bytes().await {
-                            Ok(bytes) => {
+                        match read_skin_body(app, &request, response, &active.token).await {
+                            Ok(None) => return cancelled_download(Some(&download_path)),
+                            Ok(Some(bytes)) => {
                                 // Save file
                                 if let Err(e) = fs::write(&download_path, &bytes).await {
                                     println!("[MOD-DOWNLOAD] Failed to write {}: {}", file_type, e);
@@ -402,6 +820,27 @@ pub async fn download_skin(request: SkinDownloadRequest) -> DownloadResult {
                                 println!("[MOD-DOWNLOAD] {} saved: {:?} ({} bytes)", 
                                          file_type.to_uppercase(), download_path, bytes.len());
                                 
+                                // [CHECKSUM] Truncated or tampered archives never reach extraction
+                                if let Some(ref expected) = request.expected_sha256 {
+                                    let actual = crate::file_hash::hash_bytes(&bytes);
+                                    if !actual.eq_ignore_ascii_case(expected.trim()) {
+                                        let _ = std::fs::remove_file(&download_path);
+                                        println!("[MOD-DOWNLOAD] checksum mismatch for {}: expected {}, got {}", 
+                                                 file_type, expected, actual);
+                                        checksum_failed = true;
+                                        // Same URL serves the same bytes - move on to the next format/mirror
+                                        break;
+                                    }
+                                }
+                                
+                                // [PRE-CHECK] Reject corrupt archives before touching the mod folder
+                                if !crate::mod_archive::archive_is_valid(&download_path) {
+                                    let _ = std::fs::remove_file(&download_path);
+                                    println!("[MOD-DOWNLOAD] Corrupt {} archive - retrying", file_type);
+                                    corrupt_download = true;
+                                    continue;
+                                }
+                                
                                 // Clean existing folder if any
                                 if mod_folder.exists() {
                                     let _ = std::fs::remove_dir_all(&mod_folder);
@@ -414,35 +853,35 @@ pub async fn download_skin(request: SkinDownloadRequest) -> DownloadResult {
                                         success: false,
                                         path: None,
                                         error: Some(format!("Failed to create mod folder: {}", e)),
+                                        extract_stats: None,
                                     };
                                 }
                                 
-                                // Extract based on file type
-                                if file_type == "zip" {
-                                    if let Err(e) = extract_zip(&download_path, &mod_folder) {
-                                        let _ = std::fs::remove_file(&download_path);
-                                        println!("[MOD-DOWNLOAD] ZIP extraction failed: {}", e);
-                                        continue;
-                                    }
-                                } else {
-                                    // .fantome is also a ZIP file, extract the same way
-                                    if let Err(e) = extract_zip(&download_path, &mod_folder) {
+                                // Extract based on file type - .fantome is also a ZIP file, extract the same way
+                                let stats = match extract_zip(&download_path, &mod_folder) {
+                                    Ok(stats) => stats,
+                                    Err(e) => {
                                         let _ = std::fs::remove_file(&download_path);
-                                        println!("[MOD-DOWNLOAD] FANTOME extraction failed: {}", e);
+                                        println!("[MOD-DOWNLOAD] {} extraction failed: {}", file_type.to_uppercase(), e);
                                         continue;
                                     }
-                                }
+                                };
                                 
                                 // Clean up downloaded file
                                 let _ = std::fs::remove_file(&download_path);
                                 
+                                println!("[MOD-DOWNLOAD] Downloaded from mirror: {}", mirror);
                                 return DownloadResult {
                                     success: true,
                                     path: Some(mod_folder.to_string_lossy().to_string()),
                                     error: None,
+                                    extract_stats: Some(stats),
                                 };
                             }
-                            Err(e) => println!("[MOD-DOWNLOAD] Failed to read response: {}", e),
+                            Err(e) => {
+                                println!("[MOD-DOWNLOAD] Failed to read response: {}", e);
+                                network_error = true;
+                            }
                         }
                     } else {
                         let status = response.status().as_u16();
@@ -452,9 +891,19 @@ pub async fn download_skin(request: SkinDownloadRequest) -> DownloadResult {
                             // File not found, try next format
                             break;
                         }
+                        
+                        // GitHub answers rate limits with 403 or 429 - retrying right away will not help
+                        if status == 403 || status == 429 {
+                            rate_limited = true;
+                            break;
+                        }
+                        network_error = true;
                     }
                 }
-                Err(e) => println!("[MOD-DOWNLOAD] Request failed: {}", e),
+                Err(e) => {
+                    println!("[MOD-DOWNLOAD] Request failed: {}", e);
+                    network_error = true;
+                }
             }
             
             if attempts < max_attempts {
@@ -463,111 +912,430 @@ pub async fn download_skin(request: SkinDownloadRequest) -> DownloadResult {
         }
     }
     
-    // Both .zip and .fantome failed - return user-friendly error
+    // Both .zip and .fantome failed - return the most specific error code
+    // A plain 404 on both only counts as "not available" when nothing else went wrong
+    let error = if checksum_failed {
+        SKIN_CHECKSUM_FAILED
+    } else if corrupt_download {
+        crate::mod_archive::CORRUPT_ARCHIVE
+    } else if rate_limited {
+        SKIN_RATE_LIMITED
+    } else if network_error {
+        SKIN_NETWORK_ERROR
+    } else {
+        SKIN_NOT_AVAILABLE
+    };
+    println!("[MOD-DOWNLOAD] Skin download failed: {}", error);
+    
     DownloadResult {
         success: false,
         path: None,
-        error: Some("SKIN_NOT_FOUND".to_string()),
+        error: Some(error.to_string()),
+        extract_stats: None,
     }
 }
 
 
-// [COMMAND] Activate mods using mod-tools.exe
-// [SIMPLE-CACHE] Import once, reuse always - no hash files
+// [COMMAND] Abort in-flight downloads of a skin - chroma/form variants of it included
+// Returns false when no matching download was active
 #[tauri::command]
-pub async fn activate_mods(mods: Vec<ModItem>, game_path: String) -> ActivationResult {
-    println!("[MOD-ACTIVATE] Starting activation for {} mods", mods.len());
-    println!("[MOD-ACTIVATE] Game path: {}", game_path);
+pub async fn cancel_download(champion_id: i32, skin_id: i32) -> bool {
+    let key = format!("{}_{}", champion_id, skin_id);
+    let prefix = format!("{}_", key);
     
-    // Find managers directory
-    let managers_dir = match get_managers_directory() {
-        Some(dir) => dir,
-        None => {
-            return ActivationResult {
-                success: false,
-                message: String::new(),
-                error: Some("managers directory not found - mod-tools.exe missing".to_string()),
-                vanguard_blocked: false,
-            };
+    let mut cancelled = 0;
+    if let Ok(active) = ACTIVE_DOWNLOADS.lock() {
+        for (name, (_, token)) in active.iter() {
+            if *name == key || name.starts_with(&prefix) {
+                token.cancel();
+                cancelled += 1;
+            }
         }
-    };
-    
-    let mod_tools = managers_dir.join("mod-tools.exe");
-    println!("[MOD-ACTIVATE] Using mod-tools: {:?}", mod_tools);
+    }
     
-    // Create directories - preserve everything, NEVER delete
-    let overlay_dir = get_overlay_directory();
-    let installed_dir = overlay_dir.join("installed");
-    let profile_dir = overlay_dir.join("profile");
+    println!("[MOD-DOWNLOAD] Cancel {}: {} active downloads signalled", key, cancelled);
+    cancelled > 0
+}
+
+// [COMMAND] Check skin repository reachability - separates "repo down" from "skin missing"
+#[tauri::command]
+pub async fn check_skin_repo() -> RepoStatus {
+    println!("[MOD-DOWNLOAD] Checking skin repository reachability...");
     
-    // [PERSISTENT] Create directories if not exist
-    std::fs::create_dir_all(&installed_dir).ok();
-    std::fs::create_dir_all(&profile_dir).ok();
-    println!("[MOD-ACTIVATE] Using overlay directory: {:?}", overlay_dir);
+    let client = Client::builder()
+        .timeout(std::time::Duration::from_secs(10))
+        .connect_timeout(std::time::Duration::from_secs(5))
+        .build()
+        .unwrap_or_else(|_| Client::new());
     
-    // Import each mod - skip if already in installed cache
-    let game_arg = format!("--game:{}", game_path);
+    // [PROBE] Same host downloads use - the unauthenticated API's 60/hour limit would read as "repo down"
+    let base_url = skin_base_urls().into_iter().next().unwrap_or_else(skin_base_url);
+    let probe_url = format!("{}/{}", base_url.trim_end_matches('/'), SKIN_REPO_PROBE_PATH);
     
-    // [CACHE] Build map of existing installed mods
-    let mut existing_mods: std::collections::HashMap<String, PathBuf> = std::collections::HashMap::new();
-    let mut duplicate_folders: Vec<PathBuf> = Vec::new();
+    let started = std::time::Instant::now();
+    let response = client
+        .head(&probe_url)
+        .header("User-Agent", "Wildflover")
+        .send()
+        .await;
+    let latency_ms = started.elapsed().as_millis() as u64;
     
-    if let Ok(entries) = std::fs::read_dir(&installed_dir) {
-        // [DUPLICATE-DETECTION] Track all mods and find duplicates
-        let mut base_name_map: std::collections::HashMap<String, Vec<(String, PathBuf)>> = std::collections::HashMap::new();
-        
-        for entry in entries.filter_map(|e| e.ok()) {
-            let dir_name = entry.file_name().to_string_lossy().to_string();
-            let meta_path = entry.path().join("META").join("info.json");
-            
-            if meta_path.exists() || entry.path().join("WAD").exists() {
-                // Extract base name (e.g., "103_103085" from "mod_0_103_103085")
-                let base_name = if let Some(captures) = dir_name.strip_prefix("mod_") {
-                    // Format: mod_X_baseName -> extract baseName
-                    if let Some(idx) = captures.find('_') {
-                        captures[idx + 1..].to_string()
-                    } else {
-                        dir_name.clone()
-                    }
+    let status = match response {
+        Ok(resp) => {
+            let code = resp.status();
+            // [RATE-LIMIT] Throttled responses say nothing about the repo itself
+            let rate_limited = code.as_u16() == 403 || code.as_u16() == 429;
+            RepoStatus {
+                reachable: true,
+                repo_available: code.is_success() || rate_limited,
+                rate_limited,
+                status_code: Some(code.as_u16()),
+                latency_ms: Some(latency_ms),
+                error: if code.is_success() {
+                    None
+                } else if rate_limited {
+                    Some(format!("{}: HTTP {} - try again later", SKIN_RATE_LIMITED, code))
                 } else {
-                    dir_name.clone()
-                };
-                
-                base_name_map
-                    .entry(base_name)
-                    .or_insert_with(Vec::new)
-                    .push((dir_name.clone(), entry.path()));
-                    
-                existing_mods.insert(dir_name.clone(), entry.path());
-                println!("[MOD-CACHE] Found cached: {}", dir_name);
-            } else if dir_name.starts_with("temp_") {
-                // [CLEANUP] Remove leftover temp folders from failed operations
-                println!("[MOD-CACHE] Cleaning temp folder: {}", dir_name);
-                let _ = std::fs::remove_dir_all(entry.path());
-            }
-        }
-        
-        // [DUPLICATE-CLEANUP] Remove duplicate mods (keep first occurrence)
-        for (base_name, folders) in base_name_map.iter() {
-            if folders.len() > 1 {
-                println!("[MOD-CACHE] Found {} duplicates for {}", folders.len(), base_name);
-                // Keep first, mark others for deletion
-                for (_, path) in folders.iter().skip(1) {
-                    duplicate_folders.push(path.clone());
-                }
+                    Some(format!("HTTP {}", code))
+                },
             }
         }
-    }
+        Err(e) => RepoStatus {
+            reachable: false,
+            repo_available: false,
+            rate_limited: false,
+            status_code: None,
+            latency_ms: None,
+            error: Some(if e.is_timeout() { "Request timed out".to_string() } else { e.to_string() }),
+        },
+    };
     
-    // [DUPLICATE-REMOVAL] Delete duplicate folders to prevent crashes
-    if !duplicate_folders.is_empty() {
-        println!("[MOD-CACHE] Removing {} duplicate mod folders", duplicate_folders.len());
-        for folder in &duplicate_folders {
-            if let Err(e) = std::fs::remove_dir_all(folder) {
-                println!("[MOD-CACHE] WARN: Failed to remove duplicate: {:?} - {}", folder, e);
-            } else {
-                println!("[MOD-CACHE] Removed duplicate: {:?}", folder);
-                // Remove from existing_mods map
+    println!("[MOD-DOWNLOAD] Skin repo: reachable={} available={} rate_limited={} status={:?} latency={:?}ms",
+             status.reachable, status.repo_available, status.rate_limited, status.status_code, status.latency_ms);
+    
+    status
+}
+
+// [FUNC] Single owner/repo/branch segment - letters, digits, '-', '_' and '.'
+fn is_valid_repo_segment(value: &str) -> bool {
+    !value.is_empty()
+        && value != "."
+        && value != ".."
+        && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
+}
+
+// [COMMAND] Get configured skin repository location
+#[tauri::command]
+pub async fn get_skin_repo() -> crate::settings::SkinRepoConfig {
+    crate::settings::load_settings().skin_repo
+}
+
+// [COMMAND] Set skin repository location - verified with a test fetch before saving
+#[tauri::command]
+pub async fn set_skin_repo(owner: String, repo: String, branch: String, subpath: String) -> Result<crate::settings::SkinRepoConfig, String> {
+    let subpath = subpath.trim().trim_matches('/').to_string();
+    let config = crate::settings::SkinRepoConfig {
+        owner: owner.trim().to_string(),
+        repo: repo.trim().to_string(),
+        branch: branch.trim().to_string(),
+        subpath,
+    };
+    
+    if !is_valid_repo_segment(&config.owner) || !is_valid_repo_segment(&config.repo) {
+        return Err("Invalid repository owner or name".to_string());
+    }
+    if !config.branch.split('/').all(is_valid_repo_segment) {
+        return Err("Invalid branch name".to_string());
+    }
+    if !config.subpath.is_empty() && !config.subpath.split('/').all(is_valid_repo_segment) {
+        return Err("Invalid skins path".to_string());
+    }
+    
+    // [TEST-FETCH] Skins folder must exist on the given branch
+    let test_url = format!("https://api.github.com/repos/{}/{}/contents/{}?ref={}",
+                           config.owner, config.repo, config.subpath, config.branch);
+    println!("[MOD-DOWNLOAD] Testing skin repo: {}", test_url);
+    
+    let client = Client::builder()
+        .timeout(std::time::Duration::from_secs(15))
+        .build()
+        .unwrap_or_else(|_| Client::new());
+    
+    let response = client
+        .get(&test_url)
+        .header("User-Agent", "Wildflover")
+        .header("Accept", "application/vnd.github+json")
+        .send()
+        .await
+        .map_err(|e| format!("Skin repo unreachable: {}", e))?;
+    
+    if !response.status().is_success() {
+        return Err(format!("Skin repo test fetch failed: HTTP {}", response.status()));
+    }
+    
+    crate::settings::update_settings(|s| s.skin_repo = config.clone())?;
+    println!("[MOD-DOWNLOAD] Skin repo set to {}/{}@{}/{}", config.owner, config.repo, config.branch, config.subpath);
+    
+    Ok(config)
+}
+
+// [COMMAND] Get configured skin mirrors
+#[tauri::command]
+pub async fn get_skin_mirrors() -> Vec<String> {
+    load_skin_mirrors()
+}
+
+// [COMMAND] Set ordered skin mirror base URLs - an empty list restores the skin repo default
+// Each mirror must serve the same {champion}/{skin}/{skin}.zip layout as the skins folder
+#[tauri::command]
+pub async fn set_skin_mirrors(mirrors: Vec<String>) -> Result<Vec<String>, String> {
+    let mut cleaned: Vec<String> = Vec::new();
+    for mirror in mirrors {
+        let mirror = mirror.trim().trim_end_matches('/').to_string();
+        if mirror.is_empty() {
+            continue;
+        }
+        if !mirror.starts_with("https://") && !mirror.starts_with("http://") {
+            return Err(format!("Mirror must be an http(s) URL: {}", mirror));
+        }
+        if !cleaned.contains(&mirror) {
+            cleaned.push(mirror);
+        }
+    }
+    
+    let config_path = get_skin_mirrors_config();
+    if let Some(parent) = config_path.parent() {
+        std::fs::create_dir_all(parent).ok();
+    }
+    let json = serde_json::to_string_pretty(&cleaned)
+        .map_err(|e| format!("Failed to serialize mirrors: {}", e))?;
+    std::fs::write(&config_path, json).map_err(|e| format!("Failed to save mirrors: {}", e))?;
+    
+    println!("[MOD-DOWNLOAD] Skin mirrors set: {:?}", cleaned);
+    Ok(cleaned)
+}
+
+// [FUNC] Get last activation record location
+fn get_last_activation_file() -> PathBuf {
+    get_overlay_directory().join("last_activation.json")
+}
+
+// [FUNC] Persist activation outcome so the UI can re-query it later
+fn save_last_activation(result: &ActivationResult, mods: Vec<String>, game_path: &str) {
+    let record = LastActivation {
+        result: result.clone(),
+        mods,
+        game_path: game_path.to_string(),
+        timestamp: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+    };
+    
+    let file = get_last_activation_file();
+    if let Some(parent) = file.parent() {
+        std::fs::create_dir_all(parent).ok();
+    }
+    if let Ok(json) = serde_json::to_string_pretty(&record) {
+        if let Err(e) = std::fs::write(&file, json) {
+            println!("[MOD-ACTIVATE] WARN: Failed to save last activation: {}", e);
+        }
+    }
+}
+
+// [FUNC] Read last activation record
+pub fn load_last_activation() -> Option<LastActivation> {
+    let content = std::fs::read_to_string(get_last_activation_file()).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+// [COMMAND] Get the outcome of the most recent activation
+#[tauri::command]
+pub async fn get_last_activation() -> Option<LastActivation> {
+    load_last_activation()
+}
+
+// [COMMAND] Activate mods using mod-tools.exe
+// Outcome is persisted for get_last_activation
+// force_reimport skips the installed cache so mods updated in place are imported fresh
+#[tauri::command]
+pub async fn activate_mods(
+    mods: Vec<ModItem>,
+    game_path: String,
+    conflict_resolutions: Option<Vec<ConflictResolution>>,
+    force_reimport: Option<bool>,
+) -> ActivationResult {
+    let mod_names: Vec<String> = mods.iter().map(|m| m.name.clone()).collect();
+    let result = run_activation(mods, &game_path, conflict_resolutions, force_reimport.unwrap_or(false)).await;
+    save_last_activation(&result, mod_names, &game_path);
+    result
+}
+
+// [COMMAND] Retry only the mods that failed in the previous activation
+// Mods already built into the profile are reused from the installed cache
+#[tauri::command]
+pub async fn retry_failed_mods(failed: Vec<ModItem>, game_path: String) -> ActivationResult {
+    println!("[MOD-ACTIVATE] Retrying {} failed mods", failed.len());
+    
+    let overlay_dir = get_overlay_directory();
+    let installed_dir = overlay_dir.join("installed");
+    
+    // [PROFILE-MODS] Previously built mods point straight at their cache folders (cache HIT)
+    let mut mods: Vec<ModItem> = std::fs::read_to_string(overlay_dir.join("profile.mods"))
+        .unwrap_or_default()
+        .lines()
+        .map(|name| name.trim())
+        .filter(|name| !name.is_empty() && installed_dir.join(name).is_dir())
+        .map(|name| ModItem {
+            name: name.to_string(),
+            path: installed_dir.join(name).to_string_lossy().to_string(),
+            _is_custom: false,
+        })
+        .collect();
+    
+    mods.extend(failed);
+    
+    let mod_names: Vec<String> = mods.iter().map(|m| m.name.clone()).collect();
+    let result = run_activation(mods, &game_path, None, false).await;
+    save_last_activation(&result, mod_names, &game_path);
+    result
+}
+
+// [FUNC] Activation pipeline - import, mkoverlay, runoverlay
+// [SIMPLE-CACHE] Import once, reuse always - no hash files
+async fn run_activation(
+    mods: Vec<ModItem>,
+    game_path: &str,
+    conflict_resolutions: Option<Vec<ConflictResolution>>,
+    force_reimport: bool,
+) -> ActivationResult {
+    println!("[MOD-ACTIVATE] Starting activation for {} mods (force reimport: {})", mods.len(), force_reimport);
+    println!("[MOD-ACTIVATE] Game path: {}", game_path);
+    
+    // [TIMING] Phase durations are returned so slow phases show up in bug reports
+    let started = std::time::Instant::now();
+    let mut timings = ActivationTimings::default();
+    
+    let conflict_resolutions = conflict_resolutions.unwrap_or_default();
+    if let Err(e) = validate_conflict_resolutions(&mods, &conflict_resolutions) {
+        return ActivationResult {
+            success: false,
+            message: String::new(),
+            error: Some(e),
+            vanguard_blocked: false,
+            error_code: None,
+            failed_mods: Vec::new(),
+            timings: None,
+        };
+    }
+    
+    // Find managers directory
+    let managers_dir = match get_managers_directory() {
+        Some(dir) => dir,
+        None => {
+            return ActivationResult {
+                success: false,
+                message: String::new(),
+                error: Some("managers directory not found - mod-tools.exe missing".to_string()),
+                vanguard_blocked: false,
+                error_code: None,
+                failed_mods: Vec::new(),
+                timings: None,
+            };
+        }
+    };
+    
+    let mod_tools = managers_dir.join("mod-tools.exe");
+    println!("[MOD-ACTIVATE] Using mod-tools: {:?}", mod_tools);
+    
+    // Create directories - preserve everything, NEVER delete
+    let overlay_dir = get_overlay_directory();
+    let installed_dir = overlay_dir.join("installed");
+    let profile_dir = overlay_dir.join("profile");
+    
+    // [PERSISTENT] Create directories if not exist
+    std::fs::create_dir_all(&installed_dir).ok();
+    std::fs::create_dir_all(&profile_dir).ok();
+    println!("[MOD-ACTIVATE] Using overlay directory: {:?}", overlay_dir);
+    
+    // [LOCK-CHECK] Leftover locks make mkoverlay fail with opaque errors
+    let writable = ensure_overlay_writable_inner(true);
+    if !writable.success {
+        return ActivationResult {
+            success: false,
+            message: format!("{} locked files, e.g. {}", writable.locked_files.len(),
+                             writable.locked_files.first().cloned().unwrap_or_default()),
+            error: Some(overlay_error_message(OVERLAY_LOCKED).to_string()),
+            vanguard_blocked: false,
+            error_code: Some(OVERLAY_LOCKED.to_string()),
+            failed_mods: Vec::new(),
+            timings: None,
+        };
+    }
+    
+    // Import each mod - skip if already in installed cache
+    let game_arg = format!("--game:{}", game_path);
+    
+    // [CACHE] Build map of existing installed mods
+    let mut existing_mods: std::collections::HashMap<String, PathBuf> = std::collections::HashMap::new();
+    let mut duplicate_folders: Vec<PathBuf> = Vec::new();
+    
+    if let Ok(entries) = std::fs::read_dir(&installed_dir) {
+        // [DUPLICATE-DETECTION] Track all mods and find duplicates
+        let mut base_name_map: std::collections::HashMap<String, Vec<(String, PathBuf)>> = std::collections::HashMap::new();
+        
+        for entry in entries.filter_map(|e| e.ok()) {
+            let dir_name = entry.file_name().to_string_lossy().to_string();
+            let meta_path = entry.path().join("META").join("info.json");
+            
+            if meta_path.exists() || entry.path().join("WAD").exists() {
+                // Extract base name (e.g., "103_103085" from "mod_0_103_103085")
+                let base_name = if let Some(captures) = dir_name.strip_prefix("mod_") {
+                    // Format: mod_X_baseName -> extract baseName
+                    if let Some(idx) = captures.find('_') {
+                        captures[idx + 1..].to_string()
+                    } else {
+                        dir_name.clone()
+                    }
+                } else {
+                    dir_name.clone()
+                };
+                
+                base_name_map
+                    .entry(base_name)
+                    .or_insert_with(Vec::new)
+                    .push((dir_name.clone(), entry.path()));
+                    
+                existing_mods.insert(dir_name.clone(), entry.path());
+                println!("[MOD-CACHE] Found cached: {}", dir_name);
+            } else if dir_name.starts_with("temp_") {
+                // [CLEANUP] Remove leftover temp folders from failed operations
+                println!("[MOD-CACHE] Cleaning temp folder: {}", dir_name);
+                let _ = std::fs::remove_dir_all(entry.path());
+            }
+        }
+        
+        // [DUPLICATE-CLEANUP] Remove duplicate mods (keep first occurrence)
+        for (base_name, folders) in base_name_map.iter() {
+            if folders.len() > 1 {
+                println!("[MOD-CACHE] Found {} duplicates for {}", folders.len(), base_name);
+                // Keep first, mark others for deletion
+                for (_, path) in folders.iter().skip(1) {
+                    duplicate_folders.push(path.clone());
+                }
+            }
+        }
+    }
+    
+    // [DUPLICATE-REMOVAL] Delete duplicate folders to prevent crashes
+    if !duplicate_folders.is_empty() {
+        println!("[MOD-CACHE] Removing {} duplicate mod folders", duplicate_folders.len());
+        for folder in &duplicate_folders {
+            if let Err(e) = std::fs::remove_dir_all(folder) {
+                println!("[MOD-CACHE] WARN: Failed to remove duplicate: {:?} - {}", folder, e);
+            } else {
+                println!("[MOD-CACHE] Removed duplicate: {:?}", folder);
+                // Remove from existing_mods map
                 if let Some(name) = folder.file_name() {
                     existing_mods.remove(&name.to_string_lossy().to_string());
                 }
@@ -577,16 +1345,31 @@ pub async fn activate_mods(mods: Vec<ModItem>, game_path: String) -> ActivationR
     
     println!("[MOD-CACHE] {} mods in cache (after cleanup)", existing_mods.len());
     
-    // Track which mods we're using this session
-    let mut session_mods: Vec<String> = Vec::new();
+    // Track which mods we're using this session - keyed by selection index to keep priority order
+    let mut session_mods: Vec<(usize, String)> = Vec::new();
+    
+    // [FAILED-MODS] Mods skipped this session - returned so the UI can offer a retry
+    let mut failed_mods: Vec<FailedMod> = Vec::new();
+    let failed_mod = |mod_item: &ModItem, reason: String| FailedMod {
+        name: mod_item.name.clone(),
+        path: mod_item.path.clone(),
+        reason,
+    };
+    
+    // [PARALLEL-IMPORT] File imports are queued and run concurrently after the scan
+    let mut pending_imports: Vec<(usize, String, PathBuf, PathBuf)> = Vec::new();
     
-    for (_index, mod_item) in mods.iter().enumerate() {
+    for (index, mod_item) in mods.iter().enumerate() {
         let src_path = PathBuf::from(&mod_item.path);
         
         // [LANGUAGE-INDEPENDENT] Use source path to generate unique mod name
         // This ensures same skin uses same cache regardless of UI language
         // Extract champion_skin ID from path like "103_103085" or use hash
-        let mod_name = if let Some(file_name) = src_path.file_name() {
+        let mod_name = if let Some(installed_name) = installed_folder_name(&src_path, &installed_dir) {
+            // [INSTALLED] Source already is a cache folder (retry, profile reuse) - keep its name
+            println!("[MOD-NAME] Installed mod: {}", installed_name);
+            installed_name
+        } else if let Some(file_name) = src_path.file_name() {
             let name_str = file_name.to_string_lossy().to_string();
             
             // [MARKETPLACE-FIX] Check if this is a marketplace mod
@@ -662,11 +1445,11 @@ pub async fn activate_mods(mods: Vec<ModItem>, game_path: String) -> ActivationR
                     name_without_ext
                 } else {
                     // Custom mod - use sanitized name (preserve original structure)
-                    let custom_name = name_without_ext
+                    let custom_name = namespace_custom_name(name_without_ext
                         .chars()
                         .filter(|c| c.is_alphanumeric() || *c == '_' || *c == '-' || *c == ' ')
                         .collect::<String>()
-                        .replace(' ', "_");
+                        .replace(' ', "_"));
                     println!("[MOD-NAME] Custom mod: {}", custom_name);
                     custom_name
                 }
@@ -694,13 +1477,16 @@ pub async fn activate_mods(mods: Vec<ModItem>, game_path: String) -> ActivationR
         let target_dir = installed_dir.join(&mod_name);
         
         // [CACHE-CHECK] If mod already exists with valid content, REUSE it (no re-import)
-        if target_dir.exists() {
+        // Forced reimport falls through to [CLEAN], which removes the stale folder once the source is confirmed
+        // A mod whose source IS the installed folder can't be reimported - it is always reused
+        let reimport = force_reimport && src_path != target_dir;
+        if target_dir.exists() && !reimport {
             let has_wad = target_dir.join("WAD").exists();
             let has_meta = target_dir.join("META").exists();
             
             if has_wad || has_meta {
                 println!("[MOD-CACHE] Cache HIT - reusing: {}", mod_name);
-                session_mods.push(mod_name);
+                session_mods.push((index, mod_name));
                 continue;  // Skip import entirely
             }
         }
@@ -721,12 +1507,13 @@ pub async fn activate_mods(mods: Vec<ModItem>, game_path: String) -> ActivationR
             if alt_path.exists() {
                 println!("[MOD-ACTIVATE] DEBUG: Alternative path exists: {:?}", alt_path);
             }
+            failed_mods.push(failed_mod(mod_item, "Source file not found".to_string()));
             continue;
         }
         
         println!("[MOD-CACHE] Cache MISS - importing: {}", mod_name);
         
-        // [CLEAN] Only remove if exists but invalid (no WAD/META)
+        // [CLEAN] Remove if exists but invalid (no WAD/META) or a forced reimport was requested
         if target_dir.exists() {
             let _ = std::fs::remove_dir_all(&target_dir);
         }
@@ -736,53 +1523,103 @@ pub async fn activate_mods(mods: Vec<ModItem>, game_path: String) -> ActivationR
             println!("[MOD-ACTIVATE] Copying: {} -> {}", src_path.display(), mod_name);
             if let Err(e) = copy_dir_recursive(&src_path, &target_dir) {
                 println!("[MOD-ACTIVATE] WARN: Copy failed: {}", e);
+                failed_mods.push(failed_mod(mod_item, format!("Copy failed: {}", e)));
+                continue;
+            }
+            session_mods.push((index, mod_name));
+        } else if is_raw_wad_file(&src_path) {
+            // [SINGLE-WAD] mod-tools import only takes archives/folders - build the folder layout ourselves
+            println!("[MOD-ACTIVATE] Wrapping raw WAD: {} -> {}", src_path.display(), mod_name);
+            if let Err(e) = wrap_wad_file(&src_path, &target_dir, &mod_item.name) {
+                println!("[MOD-ACTIVATE] WARN: WAD wrap failed: {}", e);
+                let _ = std::fs::remove_dir_all(&target_dir);
+                failed_mods.push(failed_mod(mod_item, e));
                 continue;
             }
-            session_mods.push(mod_name);
+            session_mods.push((index, mod_name));
         } else if src_path.is_file() {
-            println!("[MOD-ACTIVATE] Importing: {} -> {}", src_path.display(), mod_name);
-            
-            let mut cmd = Command::new(&mod_tools);
-            cmd.args(&[
-                "import",
-                src_path.to_str().unwrap_or(""),
-                target_dir.to_str().unwrap_or(""),
-                &game_arg,
-            ]);
-            
-            // [WINDOWS] Hide console window
-            #[cfg(windows)]
-            cmd.creation_flags(CREATE_NO_WINDOW);
-            
-            let import_result = cmd.output();
+            pending_imports.push((index, mod_name, src_path, target_dir));
+        }
+    }
+    
+    // [PARALLEL-IMPORT] Run queued imports bounded by the user-configured concurrency
+    if !pending_imports.is_empty() {
+        let concurrency = crate::settings::get_import_concurrency().await as usize;
+        println!("[MOD-ACTIVATE] Importing {} mods ({} concurrent)", pending_imports.len(), concurrency);
+        
+        let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(concurrency));
+        let mut tasks = tokio::task::JoinSet::new();
+        
+        // [UNRESOLVED] Indexes without an outcome yet - a panicked task is still reported as failed
+        let mut unresolved: std::collections::HashSet<usize> =
+            pending_imports.iter().map(|(index, _, _, _)| *index).collect();
+        
+        for (index, mod_name, src_path, target_dir) in pending_imports {
+            let semaphore = semaphore.clone();
+            let mod_tools = mod_tools.clone();
+            let game_arg = game_arg.clone();
             
-            match import_result {
-                Ok(output) => {
-                    if output.status.success() {
-                        println!("[MOD-ACTIVATE] Imported: {}", mod_name);
-                        session_mods.push(mod_name);
-                    } else {
-                        let stderr = String::from_utf8_lossy(&output.stderr);
-                        println!("[MOD-ACTIVATE] WARN: Import failed: {}", stderr);
-                    }
+            tasks.spawn(async move {
+                let _permit = semaphore.acquire_owned().await.ok();
+                let outcome = tokio::task::spawn_blocking(move || {
+                    import_mod_file(&mod_tools, &src_path, &target_dir, &game_arg, &mod_name)
+                        .map(|()| mod_name)
+                })
+                .await
+                .unwrap_or_else(|e| Err(format!("Import task failed: {}", e)));
+                (index, outcome)
+            });
+        }
+        
+        while let Some(result) = tasks.join_next().await {
+            match result {
+                Ok((index, Ok(mod_name))) => {
+                    unresolved.remove(&index);
+                    session_mods.push((index, mod_name));
+                }
+                Ok((index, Err(reason))) => {
+                    unresolved.remove(&index);
+                    failed_mods.push(failed_mod(&mods[index], reason));
                 }
-                Err(e) => println!("[MOD-ACTIVATE] WARN: Import error: {}", e),
+                Err(e) => println!("[MOD-ACTIVATE] WARN: Import task panicked: {}", e),
             }
         }
+        
+        for index in unresolved {
+            failed_mods.push(failed_mod(&mods[index], "Import task crashed".to_string()));
+        }
+    }
+    
+    if !failed_mods.is_empty() {
+        failed_mods.sort_by(|a, b| a.name.cmp(&b.name));
+        println!("[MOD-ACTIVATE] {} mods failed and were skipped", failed_mods.len());
     }
     
-    // Use session mods for this activation
-    let imported_mods = session_mods;
+    // Use session mods for this activation - restore selection order for mkoverlay priority
+    session_mods.sort_by_key(|(index, _)| *index);
+    if let Some(order) = crate::load_order::active_load_order() {
+        apply_load_order_preset(&mut session_mods, &mods, &order);
+    }
+    apply_conflict_resolutions(&mut session_mods, &mods, &conflict_resolutions);
+    let imported_mods: Vec<String> = session_mods.into_iter().map(|(_, name)| name).collect();
+    
+    timings.import_ms = Some(started.elapsed().as_millis() as u64);
     
     if imported_mods.is_empty() {
+        timings.total_ms = started.elapsed().as_millis() as u64;
         return ActivationResult {
             success: false,
             message: String::new(),
             error: Some("No valid mods to activate".to_string()),
             vanguard_blocked: false,
+            error_code: None,
+            failed_mods,
+            timings: Some(timings),
         };
     }
     
+    let mkoverlay_started = std::time::Instant::now();
+    
     // Build mkoverlay command
     let mods_arg = format!("--mods:{}", imported_mods.join("/"));
     
@@ -794,15 +1631,18 @@ pub async fn activate_mods(mods: Vec<ModItem>, game_path: String) -> ActivationR
     // [NOTE] Profile directory is NOT deleted - mkoverlay overwrites existing files
     // This preserves cache and speeds up re-activation with same/similar mods
     
-    // [RETRY-MECHANISM] Try mkoverlay up to 3 times (bocchi-style crash prevention)
+    // [RETRY-MECHANISM] Try mkoverlay several times (bocchi-style crash prevention)
+    // Attempt count and delay come from settings - defaults to 3 attempts, 500ms apart
+    let retries = crate::settings::load_settings().mkoverlay_retries.clamped();
     let mut mkoverlay_success = false;
     let mut last_error: Option<String> = None;
     let mut is_vanguard_blocked = false;
+    let mut mkoverlay_error_code: Option<&'static str> = None;
     
-    for attempt in 1..=3 {
+    for attempt in 1..=retries.attempts {
         if attempt > 1 {
-            println!("[MOD-ACTIVATE] Retrying mkoverlay, attempt {}/3", attempt);
-            std::thread::sleep(std::time::Duration::from_millis(500));
+            println!("[MOD-ACTIVATE] Retrying mkoverlay, attempt {}/{}", attempt, retries.attempts);
+            std::thread::sleep(std::time::Duration::from_millis(retries.delay_ms));
         }
         
         let mut cmd = Command::new(&mod_tools);
@@ -836,6 +1676,7 @@ pub async fn activate_mods(mods: Vec<ModItem>, game_path: String) -> ActivationR
                     break;
                 } else {
                     is_vanguard_blocked = stderr.contains("C0000229") || stderr.contains("ah_result");
+                    mkoverlay_error_code = classify_overlay_error(&stderr);
                     last_error = Some(format!("mkoverlay failed: {}", stderr));
                     println!("[MOD-ACTIVATE] mkoverlay attempt {} failed: {}", attempt, stderr);
                 }
@@ -847,57 +1688,418 @@ pub async fn activate_mods(mods: Vec<ModItem>, game_path: String) -> ActivationR
         }
     }
     
+    timings.mkoverlay_ms = Some(mkoverlay_started.elapsed().as_millis() as u64);
+    
     if !mkoverlay_success {
+        timings.total_ms = started.elapsed().as_millis() as u64;
         return ActivationResult {
             success: false,
             message: String::new(),
             error: last_error,
             vanguard_blocked: is_vanguard_blocked,
+            error_code: mkoverlay_error_code.map(String::from),
+            failed_mods,
+            timings: Some(timings),
         };
     }
     
+    // [PROFILE-MODS] Record which mods were built into the profile
+    let profile_mods_file = overlay_dir.join("profile.mods");
+    std::fs::write(&profile_mods_file, imported_mods.join("\n")).ok();
+    
     println!("[MOD-ACTIVATE] Profile ready - starting overlay");
     
     // Start overlay process
-    start_overlay_process(&mod_tools, &overlay_dir, &profile_dir, &game_path, imported_mods.len())
+    let overlay_started = std::time::Instant::now();
+    let mut result = start_overlay_process(&mod_tools, &overlay_dir, &profile_dir, game_path, imported_mods.len());
+    timings.overlay_start_ms = Some(overlay_started.elapsed().as_millis() as u64);
+    timings.total_ms = started.elapsed().as_millis() as u64;
+    
+    println!("[MOD-ACTIVATE] Timings: import {:?}ms, mkoverlay {:?}ms, overlay start {:?}ms, total {}ms",
+             timings.import_ms, timings.mkoverlay_ms, timings.overlay_start_ms, timings.total_ms);
+    
+    result.failed_mods = failed_mods;
+    result.timings = Some(timings);
+    result
 }
 
-// [FUNC] Start overlay process - extracted for reuse
-fn start_overlay_process(
-    mod_tools: &PathBuf,
-    overlay_dir: &PathBuf,
-    profile_dir: &PathBuf,
-    game_path: &str,
-    mod_count: usize
-) -> ActivationResult {
-    let game_arg = format!("--game:{}", game_path);
-    let config_path = overlay_dir.join("profile.config");
-    let status_file = overlay_dir.join("overlay.status");
-    
-    // [DEBUG] Log profile directory contents
-    println!("[MOD-ACTIVATE] Checking profile directory contents...");
-    if let Ok(entries) = std::fs::read_dir(profile_dir) {
-        let files: Vec<_> = entries.filter_map(|e| e.ok()).collect();
-        println!("[MOD-ACTIVATE] Profile contains {} items:", files.len());
-        for entry in files.iter().take(10) {
+// [CONST] Error code returned when overlay files stay locked after recovery
+const OVERLAY_LOCKED: &str = "OVERLAY_LOCKED";
+
+// [STRUCT] Overlay writability check result
+#[derive(Serialize)]
+pub struct OverlayWritableResult {
+    pub success: bool,
+    pub locked_files: Vec<String>,
+    pub killed_processes: bool,
+    pub error: Option<String>,
+}
+
+// [FUNC] Collect files under a directory that cannot be opened for writing
+fn find_locked_files(dir: &Path, locked: &mut Vec<String>) {
+    if let Ok(entries) = std::fs::read_dir(dir) {
+        for entry in entries.filter_map(|e| e.ok()) {
             let path = entry.path();
-            let size = if path.is_file() {
-                entry.metadata().map(|m| m.len()).unwrap_or(0)
-            } else {
-                0
-            };
-            println!("[MOD-ACTIVATE]   - {} ({} bytes)", 
-                     entry.file_name().to_string_lossy(), size);
-        }
-        if files.len() > 10 {
-            println!("[MOD-ACTIVATE]   ... and {} more", files.len() - 10);
+            if path.is_dir() {
+                find_locked_files(&path, locked);
+            } else if std::fs::OpenOptions::new().write(true).open(&path).is_err() {
+                locked.push(path.to_string_lossy().to_string());
+            }
         }
-    } else {
-        println!("[MOD-ACTIVATE] WARN: Cannot read profile directory!");
     }
+}
+
+// [FUNC] Check overlay files mkoverlay/runoverlay rewrite - profile contents and control files
+fn scan_overlay_locks(overlay_dir: &Path) -> Vec<String> {
+    let mut locked: Vec<String> = Vec::new();
     
-    // Create config file for runoverlay (empty file as bocchi does)
-    std::fs::write(&config_path, "").ok();
+    for name in ["profile.config", "overlay.status", "overlay.pid", "profile.mods"] {
+        let path = overlay_dir.join(name);
+        if path.is_file() && std::fs::OpenOptions::new().write(true).open(&path).is_err() {
+            locked.push(path.to_string_lossy().to_string());
+        }
+    }
+    
+    find_locked_files(&overlay_dir.join("profile"), &mut locked);
+    locked
+}
+
+// [FUNC] Detect locked overlay files and optionally kill lingering mod-tools.exe holding them
+fn ensure_overlay_writable_inner(kill_lingering: bool) -> OverlayWritableResult {
+    let overlay_dir = get_overlay_directory();
+    let locked = scan_overlay_locks(&overlay_dir);
+    
+    if locked.is_empty() {
+        return OverlayWritableResult { success: true, locked_files: locked, killed_processes: false, error: None };
+    }
+    
+    println!("[MOD-LOCK] {} overlay files locked", locked.len());
+    for file in locked.iter().take(5) {
+        println!("[MOD-LOCK]   - {}", file);
+    }
+    
+    if !kill_lingering {
+        return OverlayWritableResult {
+            success: false,
+            locked_files: locked,
+            killed_processes: false,
+            error: Some(OVERLAY_LOCKED.to_string()),
+        };
+    }
+    
+    // [RECOVER] Release our own overlay first, then any orphaned mod-tools.exe
+    println!("[MOD-LOCK] Stopping lingering mod-tools.exe processes...");
+    shutdown_overlay_process();
+    OVERLAY_PAUSED.store(false, Ordering::SeqCst);
+    
+    #[cfg(windows)]
+    {
+        let mut cmd = Command::new("taskkill");
+        cmd.args(["/F", "/IM", "mod-tools.exe"]);
+        cmd.creation_flags(CREATE_NO_WINDOW);
+        let _ = cmd.output();
+    }
+    
+    // Give the OS a moment to release handles
+    std::thread::sleep(std::time::Duration::from_millis(500));
+    
+    let still_locked = scan_overlay_locks(&overlay_dir);
+    if still_locked.is_empty() {
+        println!("[MOD-LOCK] Overlay directory writable after recovery");
+        set_overlay_status(&overlay_dir.join("overlay.status"), "stopped", None, 0);
+        OverlayWritableResult { success: true, locked_files: Vec::new(), killed_processes: true, error: None }
+    } else {
+        println!("[MOD-LOCK] ERROR: {} files still locked after recovery", still_locked.len());
+        OverlayWritableResult {
+            success: false,
+            locked_files: still_locked,
+            killed_processes: true,
+            error: Some(OVERLAY_LOCKED.to_string()),
+        }
+    }
+}
+
+// [COMMAND] Verify overlay directory is writable - antivirus or crashed mod-tools can hold locks
+#[tauri::command]
+pub async fn ensure_overlay_writable(kill_lingering: Option<bool>) -> OverlayWritableResult {
+    ensure_overlay_writable_inner(kill_lingering.unwrap_or(true))
+}
+
+// [FUNC] Ensure every mod referenced by a resolution is part of this activation
+fn validate_conflict_resolutions(mods: &[ModItem], resolutions: &[ConflictResolution]) -> Result<(), String> {
+    let names: std::collections::HashSet<&str> = mods.iter().map(|m| m.name.as_str()).collect();
+    
+    for resolution in resolutions {
+        for name in [&resolution.mod_a, &resolution.mod_b] {
+            if !names.contains(name.as_str()) {
+                return Err(format!("Conflict resolution references mod not in activation set: {}", name));
+            }
+        }
+        if resolution.winner != resolution.mod_a && resolution.winner != resolution.mod_b {
+            return Err(format!("Conflict winner '{}' is not one of '{}' / '{}'",
+                               resolution.winner, resolution.mod_a, resolution.mod_b));
+        }
+    }
+    
+    Ok(())
+}
+
+// [FUNC] Reorder --mods to follow a load order preset
+// Mods missing from the preset keep their selection order ahead of the curated ones
+fn apply_load_order_preset(session_mods: &mut [(usize, String)], mods: &[ModItem], order: &[String]) {
+    session_mods.sort_by_key(|(index, _)| {
+        mods.get(*index)
+            .and_then(|m| order.iter().position(|name| *name == m.name))
+            .map(|pos| pos + 1)
+            .unwrap_or(0)
+    });
+    println!("[LOAD-ORDER] Applied preset order to {} mods", session_mods.len());
+}
+
+// [FUNC] Reorder --mods so each resolution winner comes after its loser (later mods override)
+fn apply_conflict_resolutions(session_mods: &mut Vec<(usize, String)>, mods: &[ModItem], resolutions: &[ConflictResolution]) {
+    let position_of = |list: &Vec<(usize, String)>, name: &str| {
+        list.iter().position(|(index, _)| mods.get(*index).map(|m| m.name == name).unwrap_or(false))
+    };
+    
+    for resolution in resolutions {
+        let loser = if resolution.winner == resolution.mod_a { &resolution.mod_b } else { &resolution.mod_a };
+        
+        // Either side may have failed to import - nothing to order then
+        let (winner_pos, loser_pos) = match (position_of(session_mods, &resolution.winner), position_of(session_mods, loser)) {
+            (Some(w), Some(l)) => (w, l),
+            _ => continue,
+        };
+        
+        if winner_pos < loser_pos {
+            let winner = session_mods.remove(winner_pos);
+            // Loser shifted left by one after the removal
+            session_mods.insert(loser_pos, winner);
+            println!("[MOD-CONFLICT] '{}' now overrides '{}'", resolution.winner, loser);
+        }
+    }
+}
+
+// [FUNC] Whether a selected custom mod is a bare .wad/.wad.client instead of an archive
+fn is_raw_wad_file(path: &Path) -> bool {
+    let lower = path.to_string_lossy().to_lowercase();
+    path.is_file() && (lower.ends_with(".wad.client") || lower.ends_with(".wad"))
+}
+
+// [FUNC] Lay out a single WAD as an installed mod: WAD/<name>.wad.client plus META/info.json
+fn wrap_wad_file(src_path: &Path, target_dir: &Path, display_name: &str) -> Result<(), String> {
+    let file_name = src_path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .ok_or_else(|| "Invalid WAD file name".to_string())?;
+    
+    // The game only loads *.wad.client - plain .wad files are renamed
+    let wad_name = if file_name.to_lowercase().ends_with(".wad.client") {
+        file_name
+    } else {
+        format!("{}.client", file_name)
+    };
+    
+    let wad_dir = target_dir.join("WAD");
+    let meta_dir = target_dir.join("META");
+    std::fs::create_dir_all(&wad_dir).map_err(|e| format!("Failed to create WAD folder: {}", e))?;
+    std::fs::create_dir_all(&meta_dir).map_err(|e| format!("Failed to create META folder: {}", e))?;
+    
+    std::fs::copy(src_path, wad_dir.join(&wad_name))
+        .map_err(|e| format!("Failed to copy WAD: {}", e))?;
+    
+    let info = serde_json::json!({
+        "Name": display_name,
+        "Author": "Unknown",
+        "Version": "1.0.0",
+        "Description": "",
+    });
+    let json = serde_json::to_string_pretty(&info).map_err(|e| e.to_string())?;
+    std::fs::write(meta_dir.join("info.json"), json)
+        .map_err(|e| format!("Failed to write info.json: {}", e))?;
+    
+    println!("[MOD-ACTIVATE] Wrapped WAD as WAD/{}", wad_name);
+    Ok(())
+}
+
+// [FUNC] Import a single mod file with mod-tools - returns the failure reason on error
+fn import_mod_file(mod_tools: &Path, src_path: &Path, target_dir: &Path, game_arg: &str, mod_name: &str) -> Result<(), String> {
+    println!("[MOD-ACTIVATE] Importing: {} -> {}", src_path.display(), mod_name);
+    
+    let mut cmd = Command::new(mod_tools);
+    cmd.args([
+        "import",
+        src_path.to_str().unwrap_or(""),
+        target_dir.to_str().unwrap_or(""),
+        game_arg,
+    ]);
+    
+    // [WINDOWS] Hide console window
+    #[cfg(windows)]
+    cmd.creation_flags(CREATE_NO_WINDOW);
+    
+    match cmd.output() {
+        Ok(output) => {
+            if output.status.success() {
+                println!("[MOD-ACTIVATE] Imported: {}", mod_name);
+                Ok(())
+            } else {
+                // mod-tools sometimes reports errors on stdout only - fall back so the reason is never blank
+                let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+                let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+                let detail = if !stderr.is_empty() {
+                    stderr
+                } else if !stdout.is_empty() {
+                    stdout
+                } else {
+                    format!("mod-tools exited with code {}", output.status.code().unwrap_or(-1))
+                };
+                println!("[MOD-ACTIVATE] WARN: Import failed for {}: {}", mod_name, detail);
+                Err(format!("Import failed: {}", detail))
+            }
+        }
+        Err(e) => {
+            println!("[MOD-ACTIVATE] WARN: Import error: {}", e);
+            Err(format!("Import error: {}", e))
+        }
+    }
+}
+
+// [FUNC] Register the app handle used for background events - called once from setup
+pub fn init_app_handle(app: AppHandle) {
+    let _ = APP_HANDLE.set(app);
+}
+
+// [FUNC] Emit an event from a background thread - no-op before setup ran
+fn emit_app_event<S: Serialize + Clone>(event: &str, payload: S) {
+    if let Some(app) = APP_HANDLE.get() {
+        let _ = app.emit(event, payload);
+    }
+}
+
+// [FUNC] Write overlay.status and notify the frontend - single place for state transitions
+fn set_overlay_status(status_file: &Path, status: &str, pid: Option<u32>, mod_count: usize) {
+    std::fs::write(status_file, status).ok();
+    emit_app_event("overlay-status-changed", OverlayStatusChanged {
+        status: status.to_string(),
+        pid,
+        mod_count,
+    });
+}
+
+// [FUNC] Start overlay process - extracted for reuse
+// User-initiated launch, so the watchdog gets a fresh restart budget
+fn start_overlay_process(
+    mod_tools: &PathBuf,
+    overlay_dir: &PathBuf,
+    profile_dir: &PathBuf,
+    game_path: &str,
+    mod_count: usize
+) -> ActivationResult {
+    launch_overlay_process(mod_tools, overlay_dir, profile_dir, game_path, mod_count, 0)
+}
+
+// [FUNC] Watch runoverlay and relaunch it with the same arguments if it dies while "running"
+// Gives up after MAX_OVERLAY_RESTARTS and marks the overlay as "crashed"
+fn start_overlay_watchdog(launch: OverlayLaunch, generation: u64, restarts: u32) {
+    std::thread::spawn(move || loop {
+        std::thread::sleep(std::time::Duration::from_secs(OVERLAY_WATCHDOG_INTERVAL_SECS));
+        
+        if is_shutting_down() || OVERLAY_GENERATION.load(Ordering::SeqCst) != generation {
+            return;
+        }
+        
+        let exit_code = match OVERLAY_PROCESS.lock() {
+            Ok(mut guard) => match guard.as_mut() {
+                Some(process) => match process.try_wait() {
+                    Ok(Some(status)) => status.code(),
+                    _ => continue,
+                },
+                None => return,
+            },
+            Err(_) => return,
+        };
+        
+        // A stop or pause in flight already rewrote the status - not a crash
+        let status_file = launch.overlay_dir.join("overlay.status");
+        let status = std::fs::read_to_string(&status_file).unwrap_or_default();
+        if status.trim() != "running" || OVERLAY_GENERATION.load(Ordering::SeqCst) != generation {
+            return;
+        }
+        
+        println!("[OVERLAY-WATCHDOG] runoverlay exited unexpectedly (code: {:?})", exit_code);
+        
+        let mut attempt = restarts;
+        while attempt < MAX_OVERLAY_RESTARTS {
+            attempt += 1;
+            println!("[OVERLAY-WATCHDOG] Restarting overlay ({}/{})", attempt, MAX_OVERLAY_RESTARTS);
+            
+            let result = launch_overlay_process(
+                &launch.mod_tools,
+                &launch.overlay_dir,
+                &launch.profile_dir,
+                &launch.game_path,
+                launch.mod_count,
+                attempt,
+            );
+            
+            if result.success {
+                emit_app_event("overlay-restarted", OverlayRestarted {
+                    attempt,
+                    max_attempts: MAX_OVERLAY_RESTARTS,
+                    exit_code,
+                });
+                return;
+            }
+            println!("[OVERLAY-WATCHDOG] Restart failed: {}", result.error.unwrap_or_default());
+        }
+        
+        // [CRASHED] Keep launch args so the user can still resume manually
+        println!("[OVERLAY-WATCHDOG] Overlay keeps crashing - giving up after {} restarts", MAX_OVERLAY_RESTARTS);
+        if let Ok(mut guard) = OVERLAY_PROCESS.lock() {
+            *guard = None;
+        }
+        set_overlay_status(&status_file, "crashed", None, launch.mod_count);
+        return;
+    });
+}
+
+// [FUNC] Spawn runoverlay and verify it stays up - restarts counts watchdog relaunches so far
+fn launch_overlay_process(
+    mod_tools: &PathBuf,
+    overlay_dir: &PathBuf,
+    profile_dir: &PathBuf,
+    game_path: &str,
+    mod_count: usize,
+    restarts: u32,
+) -> ActivationResult {
+    let game_arg = format!("--game:{}", game_path);
+    let config_path = overlay_dir.join("profile.config");
+    let status_file = overlay_dir.join("overlay.status");
+    
+    // [DEBUG] Log profile directory contents
+    println!("[MOD-ACTIVATE] Checking profile directory contents...");
+    if let Ok(entries) = std::fs::read_dir(profile_dir) {
+        let files: Vec<_> = entries.filter_map(|e| e.ok()).collect();
+        println!("[MOD-ACTIVATE] Profile contains {} items:", files.len());
+        for entry in files.iter().take(10) {
+            let path = entry.path();
+            let size = if path.is_file() {
+                entry.metadata().map(|m| m.len()).unwrap_or(0)
+            } else {
+                0
+            };
+            println!("[MOD-ACTIVATE]   - {} ({} bytes)", 
+                     entry.file_name().to_string_lossy(), size);
+        }
+        if files.len() > 10 {
+            println!("[MOD-ACTIVATE]   ... and {} more", files.len() - 10);
+        }
+    } else {
+        println!("[MOD-ACTIVATE] WARN: Cannot read profile directory!");
+    }
+    
+    // Create config file for runoverlay (empty file as bocchi does)
+    std::fs::write(&config_path, "").ok();
     
     // Mark overlay as ready
     std::fs::write(&status_file, "ready").ok();
@@ -941,7 +2143,11 @@ fn start_overlay_process(
             println!("[MOD-ACTIVATE] Overlay process spawned with PID: {}", pid);
             
             // [VERIFY] Wait briefly and check if process is still running
-            std::thread::sleep(std::time::Duration::from_millis(500));
+            // Slow machines need longer before a failing overlay exits - delay comes from settings
+            let verify_delay_ms = crate::settings::load_settings()
+                .overlay_verify_delay_ms
+                .clamp(crate::settings::MIN_OVERLAY_VERIFY_DELAY_MS, crate::settings::MAX_OVERLAY_VERIFY_DELAY_MS);
+            std::thread::sleep(std::time::Duration::from_millis(verify_delay_ms));
             
             match child.try_wait() {
                 Ok(Some(status)) => {
@@ -949,12 +2155,14 @@ fn start_overlay_process(
                     println!("[MOD-ACTIVATE] ERROR: Process exited immediately with status: {:?}", status);
                     
                     // Try to capture any output
+                    let mut captured = String::new();
                     if let Some(mut stdout) = child.stdout.take() {
                         let mut output = String::new();
                         let _ = stdout.read_to_string(&mut output);
                         if !output.is_empty() {
                             println!("[MOD-ACTIVATE] stdout: {}", output);
                         }
+                        captured.push_str(&output);
                     }
                     if let Some(mut stderr) = child.stderr.take() {
                         let mut output = String::new();
@@ -962,17 +2170,33 @@ fn start_overlay_process(
                         if !output.is_empty() {
                             println!("[MOD-ACTIVATE] stderr: {}", output);
                         }
+                        captured.push_str(&output);
                     }
                     
                     // Check for Vanguard-related exit codes
                     let exit_code = status.code().unwrap_or(-1);
                     let is_vanguard = exit_code == -1073741511 || exit_code == -1073740791; // C0000135 or C0000229
                     
+                    // [CLASSIFY] Known output patterns give a fixable message instead of a bare exit code
+                    let error_code = if is_vanguard {
+                        Some(OVERLAY_VANGUARD_BLOCKED)
+                    } else {
+                        classify_overlay_error(&captured)
+                    };
+                    let error = match error_code {
+                        Some(code) => format!("{} (code: {})", overlay_error_message(code), exit_code),
+                        None => format!("Overlay process exited immediately (code: {})", exit_code),
+                    };
+                    println!("[MOD-ACTIVATE] Overlay failure classified as {:?}", error_code);
+                    
                     return ActivationResult {
                         success: false,
                         message: String::new(),
-                        error: Some(format!("Overlay process exited immediately (code: {})", exit_code)),
-                        vanguard_blocked: is_vanguard,
+                        error: Some(error),
+                        vanguard_blocked: is_vanguard || error_code == Some(OVERLAY_VANGUARD_BLOCKED),
+                        error_code: error_code.map(String::from),
+                        failed_mods: Vec::new(),
+                        timings: None,
                     };
                 }
                 Ok(None) => {
@@ -984,12 +2208,27 @@ fn start_overlay_process(
                 }
             }
             
+            // [OUTPUT] Drain runoverlay pipes - a full pipe would stall the overlay
+            if let Ok(mut injected) = INJECTED_MODS.lock() {
+                injected.clear();
+            }
+            if let Some(stdout) = child.stdout.take() {
+                std::thread::spawn(move || read_overlay_stdout(stdout));
+            }
+            if let Some(stderr) = child.stderr.take() {
+                std::thread::spawn(move || {
+                    for line in std::io::BufRead::lines(std::io::BufReader::new(stderr)).map_while(Result::ok) {
+                        println!("[OVERLAY-ERR] {}", line);
+                    }
+                });
+            }
+            
             // Save PID for tracking
             let pid_file = overlay_dir.join("overlay.pid");
             std::fs::write(&pid_file, pid.to_string()).ok();
             
             // Update status to running
-            std::fs::write(&status_file, "running").ok();
+            set_overlay_status(&status_file, "running", Some(pid), mod_count);
             
             // [CRITICAL] Store process in global state to keep it alive
             if let Ok(mut guard) = OVERLAY_PROCESS.lock() {
@@ -1000,11 +2239,31 @@ fn start_overlay_process(
                 println!("[MOD-ACTIVATE] Process stored in global state");
             }
             
+            // [RESUME] Remember launch arguments so a paused overlay can restart
+            let launch = OverlayLaunch {
+                mod_tools: mod_tools.clone(),
+                overlay_dir: overlay_dir.clone(),
+                profile_dir: profile_dir.clone(),
+                game_path: game_path.to_string(),
+                mod_count,
+            };
+            if let Ok(mut stored) = OVERLAY_LAUNCH.lock() {
+                *stored = Some(launch.clone());
+            }
+            OVERLAY_PAUSED.store(false, Ordering::SeqCst);
+            
+            // [WATCHDOG] Replaces any watchdog of a previous launch
+            let generation = OVERLAY_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+            start_overlay_watchdog(launch, generation, restarts);
+            
             ActivationResult {
                 success: true,
                 message: format!("Overlay active - {} mods loaded", mod_count),
                 error: None,
                 vanguard_blocked: false,
+                error_code: None,
+                failed_mods: Vec::new(),
+                timings: None,
             }
         }
         Err(e) => {
@@ -1014,9 +2273,64 @@ fn start_overlay_process(
                 message: String::new(),
                 error: Some(format!("Failed to start overlay: {}", e)),
                 vanguard_blocked: false,
+                error_code: None,
+                failed_mods: Vec::new(),
+                timings: None,
+            }
+        }
+    }
+}
+
+// [FUNC] Mod name reported by an explicit runoverlay load line ("Loaded mod: X" / "Mounted mod: X")
+// Leading [tags] are skipped; lines merely mentioning a mod (errors, paths) never count as injected
+fn parse_injected_mod(line: &str) -> Option<String> {
+    let mut rest = line.trim_start();
+    while rest.starts_with('[') {
+        rest = rest.find(']').map(|end| rest[end + 1..].trim_start())?;
+    }
+    
+    // ASCII lowercasing keeps byte offsets identical, so slicing the original line stays on char boundaries
+    let lower = rest.to_ascii_lowercase();
+    for marker in ["loaded mod:", "mounted mod:"] {
+        if lower.starts_with(marker) {
+            let name = rest[marker.len()..].trim().trim_matches('"');
+            if !name.is_empty() {
+                return Some(name.to_string());
+            }
+        }
+    }
+    
+    None
+}
+
+// [FUNC] Log runoverlay stdout and record the mods it reports as injected
+fn read_overlay_stdout(stdout: std::process::ChildStdout) {
+    for line in std::io::BufRead::lines(std::io::BufReader::new(stdout)).map_while(Result::ok) {
+        println!("[OVERLAY-OUT] {}", line);
+        
+        if let Some(name) = parse_injected_mod(&line) {
+            if let Ok(mut injected) = INJECTED_MODS.lock() {
+                if !injected.contains(&name) {
+                    injected.push(name);
+                }
             }
         }
     }
+    println!("[OVERLAY-OUT] runoverlay output closed");
+}
+
+// [COMMAND] Mods the running overlay reported as injected - empty when no overlay runs
+#[tauri::command]
+pub async fn get_injected_mods() -> Vec<String> {
+    let running = OVERLAY_PROCESS
+        .lock()
+        .map(|mut guard| guard.as_mut().map(|c| matches!(c.try_wait(), Ok(None))).unwrap_or(false))
+        .unwrap_or(false);
+    if !running {
+        return Vec::new();
+    }
+    
+    INJECTED_MODS.lock().map(|injected| injected.clone()).unwrap_or_default()
 }
 
 // [FUNC] Recursively copy directory
@@ -1044,60 +2358,213 @@ fn copy_dir_recursive(src: &PathBuf, dst: &PathBuf) -> Result<(), String> {
 }
 
 // [FUNC] Get game path config file location
-fn get_game_path_config() -> PathBuf {
+pub fn get_game_path_config() -> PathBuf {
     let app_data = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
     app_data.join("Wildflover").join("game_path.txt")
 }
 
+// [FUNC] Extract the path from game_path.txt contents
+// Tolerates UTF-8/UTF-16 BOMs, surrounding quotes/whitespace and junk after the first line
+fn clean_game_path_text(bytes: &[u8]) -> String {
+    let text = if let Some(utf16) = bytes.strip_prefix(&[0xFF, 0xFE]) {
+        let units: Vec<u16> = utf16.chunks_exact(2).map(|c| u16::from_le_bytes([c[0], c[1]])).collect();
+        String::from_utf16_lossy(&units)
+    } else {
+        String::from_utf8_lossy(bytes).to_string()
+    };
+    
+    text.trim_start_matches('\u{feff}')
+        .lines()
+        .map(|line| line.trim_matches(|c: char| c.is_whitespace() || c == '"' || c == '\'' || c == '\0'))
+        .find(|line| !line.is_empty())
+        .unwrap_or("")
+        .to_string()
+}
+
+// [FUNC] Saved game path with encoding artifacts removed - None when missing or blank
+pub(crate) fn read_game_path_config() -> Option<String> {
+    std::fs::read(get_game_path_config())
+        .ok()
+        .map(|bytes| clean_game_path_text(&bytes))
+        .filter(|path| !path.is_empty())
+}
+
+// [FUNC] Validate the saved path and rewrite game_path.txt cleanly when its bytes differ
+fn repair_saved_game_path() -> Result<Option<String>, String> {
+    let config_path = get_game_path_config();
+    let raw = match std::fs::read(&config_path) {
+        Ok(raw) => raw,
+        Err(_) => return Ok(None),
+    };
+    
+    let saved_path = clean_game_path_text(&raw);
+    if !has_game_executable(Path::new(&saved_path)) {
+        return Err(format!("Saved game path is invalid: {}", saved_path));
+    }
+    
+    let real_path = canonicalize_game_path(&saved_path)?;
+    if raw != real_path.as_bytes() {
+        std::fs::write(&config_path, &real_path)
+            .map_err(|e| format!("Failed to rewrite game path: {}", e))?;
+        println!("[MOD-PATH] Rewrote game_path.txt cleanly: {}", real_path);
+    }
+    
+    Ok(Some(real_path))
+}
+
+// [FUNC] Resolve symlinks/junctions to the real game path
+// mod-tools cannot hook a path that only exists through a junction, so store the target
+pub(crate) fn canonicalize_game_path(path: &str) -> Result<String, String> {
+    let canonical = std::fs::canonicalize(path)
+        .map_err(|e| format!("Game path does not resolve to an existing folder: {} ({})", path, e))?;
+    
+    let canonical_str = canonical.to_string_lossy().to_string();
+    
+    // [WINDOWS] Strip verbatim prefix added by canonicalize - mod-tools expects plain paths
+    let plain = if let Some(rest) = canonical_str.strip_prefix("\\\\?\\UNC\\") {
+        format!("\\\\{}", rest)
+    } else if let Some(rest) = canonical_str.strip_prefix("\\\\?\\") {
+        rest.to_string()
+    } else {
+        canonical_str
+    };
+    
+    if plain != path {
+        println!("[MOD-PATH] Resolved game path: {} -> {}", path, plain);
+    }
+    
+    Ok(plain)
+}
+
 // [COMMAND] Get League of Legends game path - checks saved path first
 #[tauri::command]
 pub async fn detect_game_path() -> Option<String> {
     // [PRIORITY-1] Check saved manual path first
+    // BOMs, quotes and stray whitespace are stripped first so encoding artifacts don't cost the user their path
     let config_path = get_game_path_config();
     if config_path.exists() {
-        if let Ok(saved_path) = std::fs::read_to_string(&config_path) {
-            let saved_path = saved_path.trim().to_string();
-            let game_exe = PathBuf::from(&saved_path).join("League of Legends.exe");
-            if game_exe.exists() {
-                println!("[MOD-DETECT] Using saved game path: {}", saved_path);
-                return Some(saved_path);
-            } else {
-                println!("[MOD-DETECT] Saved path invalid, removing config");
+        match repair_saved_game_path() {
+            Ok(Some(real_path)) => {
+                println!("[MOD-DETECT] Using saved game path: {}", real_path);
+                return Some(real_path);
+            }
+            Ok(None) => {}
+            Err(e) => {
+                println!("[MOD-DETECT] {}, removing config", e);
                 let _ = std::fs::remove_file(&config_path);
             }
         }
     }
     
-    // [PRIORITY-2] Auto-detect from common paths
-    let common_paths = vec![
+    // [PRIORITY-2] Auto-detect from common paths for this platform
+    for path in game_path_candidates() {
+        if has_game_executable(&path) {
+            if let Ok(real_path) = canonicalize_game_path(&path.to_string_lossy()) {
+                println!("[MOD-DETECT] Found game at: {}", real_path);
+                return Some(real_path);
+            }
+        }
+    }
+    
+    println!("[MOD-DETECT] Game path not found automatically");
+    None
+}
+
+// [FUNC] Common Game folder locations - Windows installs and Wine/Lutris prefixes
+fn game_path_candidates() -> Vec<PathBuf> {
+    #[allow(unused_mut)]
+    let mut candidates: Vec<PathBuf> = Vec::new();
+    
+    #[cfg(windows)]
+    for path in [
         "C:\\Riot Games\\League of Legends\\Game",
         "D:\\Riot Games\\League of Legends\\Game",
         "C:\\Program Files\\Riot Games\\League of Legends\\Game",
         "C:\\Program Files (x86)\\Riot Games\\League of Legends\\Game",
         "E:\\Riot Games\\League of Legends\\Game",
         "F:\\Riot Games\\League of Legends\\Game",
-    ];
+    ] {
+        candidates.push(PathBuf::from(path));
+    }
     
-    for path in common_paths {
-        let game_exe = PathBuf::from(path).join("League of Legends.exe");
-        if game_exe.exists() {
-            println!("[MOD-DETECT] Found game at: {}", path);
-            return Some(path.to_string());
+    // [MACOS] Native client ships no League of Legends.exe - only Windows installs and Wine prefixes are detected
+    
+    // [WINE] Prefixes used by plain Wine, Lutris and the leagueoflegends-linux scripts
+    #[cfg(target_os = "linux")]
+    if let Some(home) = dirs::home_dir() {
+        let data_dir = dirs::data_local_dir().unwrap_or_else(|| home.join(".local").join("share"));
+        let prefixes = [
+            home.join(".wine"),
+            home.join("Games").join("league-of-legends"),
+            data_dir.join("leagueoflegends"),
+            data_dir.join("lutris").join("prefixes").join("league-of-legends"),
+            data_dir.join("wineprefixes").join("league-of-legends"),
+        ];
+        for prefix in prefixes {
+            candidates.push(prefix.join("drive_c").join("Riot Games").join("League of Legends").join("Game"));
         }
     }
     
-    println!("[MOD-DETECT] Game path not found automatically");
+    candidates
+}
+
+// [FUNC] Whether a folder holds League of Legends.exe - name compared case-insensitively like browse_game_path
+// Wine prefixes and case-sensitive file systems don't preserve the exact casing
+fn has_game_executable(dir: &Path) -> bool {
+    if dir.join("League of Legends.exe").exists() {
+        return true;
+    }
+    
+    std::fs::read_dir(dir)
+        .map(|entries| {
+            entries.filter_map(|e| e.ok()).any(|entry| {
+                entry.file_name().to_string_lossy().to_lowercase() == "league of legends.exe"
+                    && entry.path().is_file()
+            })
+        })
+        .unwrap_or(false)
+}
+
+// [FUNC] Folder holding League of Legends.exe - corrects the common "League of Legends" instead of "Game" pick
+pub(crate) fn resolve_game_folder(dir: &Path) -> Option<PathBuf> {
+    if has_game_executable(dir) {
+        return Some(dir.to_path_buf());
+    }
+    
+    let game_dir = dir.join("Game");
+    if has_game_executable(&game_dir) {
+        println!("[MOD-PATH] Auto-corrected game path: {} -> {}", dir.display(), game_dir.display());
+        return Some(game_dir);
+    }
+    
     None
 }
 
-// [COMMAND] Set game path manually - saves to config file
+// [FUNC] Error for a folder without the game - names both locations that were checked
+pub(crate) fn game_folder_not_found(dir: &Path) -> String {
+    format!(
+        "League of Legends.exe not found. Expected it in {} or its Game subfolder {}",
+        dir.display(),
+        dir.join("Game").display()
+    )
+}
+
+// [COMMAND] Set game path manually - saves to config file and returns the saved (possibly corrected) path
+// Picking the launcher folder (Riot Games\League of Legends) is corrected to its Game subfolder
 #[tauri::command]
-pub async fn set_game_path(path: String) -> Result<bool, String> {
-    let game_exe = PathBuf::from(&path).join("League of Legends.exe");
+pub async fn set_game_path(path: String) -> Result<String, String> {
+    let path = match resolve_game_folder(Path::new(&path)) {
+        Some(folder) => folder.to_string_lossy().to_string(),
+        None => {
+            println!("[MOD-PATH] Invalid path - League of Legends.exe not found: {}", path);
+            return Err(game_folder_not_found(Path::new(&path)));
+        }
+    };
     
-    if !game_exe.exists() {
-        println!("[MOD-PATH] Invalid path - League of Legends.exe not found: {}", path);
-        return Err("League of Legends.exe not found in selected folder".to_string());
+    // [SYMLINK] Store the real path so mod-tools gets a hookable location
+    let real_path = canonicalize_game_path(&path)?;
+    if !has_game_executable(Path::new(&real_path)) {
+        return Err(format!("Resolved game path is missing League of Legends.exe: {}", real_path));
     }
     
     let config_path = get_game_path_config();
@@ -1108,11 +2575,11 @@ pub async fn set_game_path(path: String) -> Result<bool, String> {
     }
     
     // Save path to config file
-    std::fs::write(&config_path, &path)
+    std::fs::write(&config_path, &real_path)
         .map_err(|e| format!("Failed to save game path: {}", e))?;
     
-    println!("[MOD-PATH] Game path saved: {}", path);
-    Ok(true)
+    println!("[MOD-PATH] Game path saved: {}", real_path);
+    Ok(real_path)
 }
 
 // [TYPES] Browse result for detailed response
@@ -1162,6 +2629,19 @@ pub async fn browse_game_path() -> BrowseResult {
             
             let file_name_lower = file_name.to_lowercase();
             
+            // [AUTO-FIX] Another exe in the install root (e.g. LeagueClient.exe) - use its Game subfolder
+            if file_name_lower != "league of legends.exe" {
+                if let Some(game_dir) = path.parent().and_then(resolve_game_folder) {
+                    let game_path = game_dir.to_string_lossy().to_string();
+                    return BrowseResult {
+                        success: true,
+                        path: Some(game_path),
+                        cancelled: false,
+                        error: None,
+                    };
+                }
+            }
+            
             // Verify it's the correct executable (League of Legends.exe)
             if file_name_lower == "league of legends.exe" {
                 // Return parent directory (Game folder)
@@ -1179,11 +2659,14 @@ pub async fn browse_game_path() -> BrowseResult {
             
             // Invalid file selected - not League of Legends.exe
             println!("[MOD-PATH] Invalid file selected: {} (expected: League of Legends.exe)", file_name);
+            let expected = path.parent()
+                .map(game_folder_not_found)
+                .unwrap_or_else(|| "Please select League of Legends.exe".to_string());
             BrowseResult {
                 success: false,
                 path: None,
                 cancelled: false,
-                error: Some(format!("Invalid file: {}. Please select League of Legends.exe", file_name)),
+                error: Some(format!("Invalid file: {}. {}", file_name, expected)),
             }
         }
         None => {
@@ -1198,6 +2681,13 @@ pub async fn browse_game_path() -> BrowseResult {
     }
 }
 
+// [COMMAND] Validate game_path.txt and rewrite it without BOM/quotes/whitespace
+// Unlike detect_game_path, an invalid path is reported and the file is left untouched
+#[tauri::command]
+pub async fn repair_game_path_config() -> Result<Option<String>, String> {
+    repair_saved_game_path()
+}
+
 // [COMMAND] Clear saved game path - revert to auto-detect
 #[tauri::command]
 pub async fn clear_game_path() -> bool {
@@ -1294,6 +2784,12 @@ pub async fn clear_mods_cache() -> bool {
         let _ = std::fs::remove_file(&cache_file);
     }
     
+    // Clear profile mod list - profile was removed above
+    let profile_mods_file = overlay_dir.join("profile.mods");
+    if profile_mods_file.exists() {
+        let _ = std::fs::remove_file(&profile_mods_file);
+    }
+    
     // Clear status files
     let status_file = overlay_dir.join("overlay.status");
     let pid_file = overlay_dir.join("overlay.pid");
@@ -1310,28 +2806,43 @@ pub async fn clear_cache() -> bool {
     clear_mods_cache().await
 }
 
+// [STRUCT] Cache deletion result - was_active means the overlay needs a rebuild
+#[derive(Serialize)]
+pub struct CacheDeleteResult {
+    pub success: bool,
+    pub was_active: bool,
+}
+
 // [COMMAND] Delete single cache file
 #[tauri::command]
-pub async fn delete_cache_file(path: String) -> bool {
+pub async fn delete_cache_file(path: String) -> CacheDeleteResult {
     let file_path = std::path::PathBuf::from(&path);
     
+    // [ACTIVE-CHECK] Installed mod folders listed in profile.mods are part of the running overlay
+    let installed_dir = get_overlay_directory().join("installed");
+    let was_active = file_path.parent() == Some(installed_dir.as_path())
+        && file_path
+            .file_name()
+            .map(|name| read_profile_mods().contains(&name.to_string_lossy().to_string()))
+            .unwrap_or(false);
+    
     if file_path.exists() {
         if file_path.is_dir() {
             if let Err(e) = std::fs::remove_dir_all(&file_path) {
                 println!("[MOD-CACHE] Failed to delete directory: {}", e);
-                return false;
+                return CacheDeleteResult { success: false, was_active: false };
             }
         } else {
             if let Err(e) = std::fs::remove_file(&file_path) {
                 println!("[MOD-CACHE] Failed to delete file: {}", e);
-                return false;
+                return CacheDeleteResult { success: false, was_active: false };
             }
         }
-        println!("[MOD-CACHE] Deleted: {}", path);
-        return true;
+        println!("[MOD-CACHE] Deleted: {} (was active: {})", path, was_active);
+        return CacheDeleteResult { success: true, was_active };
     }
     
-    false
+    CacheDeleteResult { success: false, was_active: false }
 }
 
 // [COMMAND] Get detailed cache info for frontend - includes mods + installed + overlay folders
@@ -1441,7 +2952,7 @@ pub async fn get_cache_info() -> CacheInfo {
 }
 
 // [FUNC] Calculate directory size recursively
-fn calculate_dir_size(path: &PathBuf) -> Result<u64, std::io::Error> {
+pub(crate) fn calculate_dir_size(path: &PathBuf) -> Result<u64, std::io::Error> {
     let mut size = 0;
     
     if path.is_dir() {
@@ -1459,15 +2970,28 @@ fn calculate_dir_size(path: &PathBuf) -> Result<u64, std::io::Error> {
     Ok(size)
 }
 
-// [COMMAND] Stop/deactivate overlay - bocchi-style graceful shutdown
-// NOTE: Does NOT delete any files - only stops the process
-#[tauri::command]
-pub async fn stop_overlay() -> ActivationResult {
-    println!("[MOD-STOP] Deactivating overlay...");
+// [FUNC] Whether the stored overlay child can still receive the graceful-shutdown newline
+// The pipe stays open while the child holds its read end, so a live child with a piped stdin is writable
+fn overlay_stdin_alive() -> bool {
+    let mut guard = match OVERLAY_PROCESS.lock() {
+        Ok(guard) => guard,
+        Err(_) => return false,
+    };
     
-    let overlay_dir = get_overlay_directory();
+    match guard.as_mut() {
+        Some(process) => process.stdin.is_some() && matches!(process.try_wait(), Ok(None)),
+        None => false,
+    }
+}
+
+// [FUNC] Shut down the stored overlay process - graceful via stdin, then force kill
+// Returns Some(true) if it exited on its own, Some(false) if killed, None if no process was stored
+fn shutdown_overlay_process() -> Option<bool> {
+    // Retire the watchdog first so the intended exit is not treated as a crash
+    OVERLAY_GENERATION.fetch_add(1, Ordering::SeqCst);
+    
+    let mut graceful = None;
     
-    // [BOCCHI-STYLE] First try graceful shutdown via stdin
     if let Ok(mut guard) = OVERLAY_PROCESS.lock() {
         if let Some(ref mut process) = *guard {
             println!("[MOD-STOP] Attempting graceful shutdown via stdin...");
@@ -1478,30 +3002,167 @@ pub async fn stop_overlay() -> ActivationResult {
                 let _ = stdin.flush();
             }
             
-            // Wait a bit for graceful shutdown
-            std::thread::sleep(std::time::Duration::from_millis(500));
-            
-            // Check if still running, force kill if needed
-            match process.try_wait() {
-                Ok(Some(status)) => {
-                    println!("[MOD-STOP] Process exited gracefully with status: {:?}", status);
-                }
-                Ok(None) => {
-                    println!("[MOD-STOP] Process still running, force killing...");
-                    let _ = process.kill();
-                }
-                Err(e) => {
-                    println!("[MOD-STOP] Error checking process: {}", e);
-                    let _ = process.kill();
+            // [POLL] Slow machines need a while to restore the game file table - wait up to the deadline
+            let deadline = std::time::Instant::now() + std::time::Duration::from_millis(OVERLAY_SHUTDOWN_TIMEOUT_MS);
+            let exited = loop {
+                match process.try_wait() {
+                    Ok(Some(status)) => {
+                        println!("[MOD-STOP] Process exited gracefully with status: {:?}", status);
+                        break true;
+                    }
+                    Ok(None) if std::time::Instant::now() < deadline => {
+                        std::thread::sleep(std::time::Duration::from_millis(OVERLAY_SHUTDOWN_POLL_MS));
+                    }
+                    Ok(None) => {
+                        println!("[MOD-STOP] Process still running after {}ms, force killing...", OVERLAY_SHUTDOWN_TIMEOUT_MS);
+                        break false;
+                    }
+                    Err(e) => {
+                        println!("[MOD-STOP] Error checking process: {}", e);
+                        break false;
+                    }
                 }
+            };
+            
+            if !exited {
+                let _ = process.kill();
             }
+            graceful = Some(exited);
         }
         *guard = None;
     }
     
-    // Force kill any remaining mod-tools.exe processes
+    graceful
+}
+
+// [FUNC] Whether the app is exiting - checked by background loops
+pub fn is_shutting_down() -> bool {
+    SHUTTING_DOWN.load(Ordering::SeqCst)
+}
+
+// [FUNC] App exit hook - stops our mod-tools child so it is not orphaned (runs once)
+pub fn shutdown_on_exit() {
+    if SHUTTING_DOWN.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    
+    println!("[MOD-STOP] App exiting - stopping overlay and background tasks");
+    
+    let had_process = OVERLAY_PROCESS.lock().map(|g| g.is_some()).unwrap_or(false);
+    shutdown_overlay_process();
+    OVERLAY_PAUSED.store(false, Ordering::SeqCst);
+    if let Ok(mut launch) = OVERLAY_LAUNCH.lock() {
+        *launch = None;
+    }
+    
+    if had_process {
+        std::fs::write(get_overlay_directory().join("overlay.status"), "stopped").ok();
+    }
+}
+
+// [COMMAND] Pause overlay - stops runoverlay but keeps the built profile for instant resume
+// cslol has no in-process pause signal, so pausing ends runoverlay and resume relaunches it
+#[tauri::command]
+pub async fn pause_overlay() -> ActivationResult {
+    println!("[MOD-PAUSE] Pausing overlay...");
+    
+    let has_process = OVERLAY_PROCESS.lock().map(|g| g.is_some()).unwrap_or(false);
+    let has_launch = OVERLAY_LAUNCH.lock().map(|g| g.is_some()).unwrap_or(false);
+    
+    if !has_process || !has_launch {
+        return ActivationResult {
+            success: false,
+            message: String::new(),
+            error: Some("Overlay is not running".to_string()),
+            vanguard_blocked: false,
+            error_code: None,
+            failed_mods: Vec::new(),
+            timings: None,
+        };
+    }
+    
+    shutdown_overlay_process();
+    OVERLAY_PAUSED.store(true, Ordering::SeqCst);
+    
+    let mod_count = OVERLAY_LAUNCH.lock().ok().and_then(|g| g.as_ref().map(|l| l.mod_count)).unwrap_or(0);
+    let status_file = get_overlay_directory().join("overlay.status");
+    set_overlay_status(&status_file, "paused", None, mod_count);
+    
+    println!("[MOD-PAUSE] Overlay paused - profile preserved");
+    
+    ActivationResult {
+        success: true,
+        message: "Overlay paused".to_string(),
+        error: None,
+        vanguard_blocked: false,
+        error_code: None,
+        failed_mods: Vec::new(),
+        timings: None,
+    }
+}
+
+// [COMMAND] Resume a paused overlay using the existing profile (no re-import/mkoverlay)
+#[tauri::command]
+pub async fn resume_overlay() -> ActivationResult {
+    println!("[MOD-PAUSE] Resuming overlay...");
+    
+    if !OVERLAY_PAUSED.load(Ordering::SeqCst) {
+        return ActivationResult {
+            success: false,
+            message: String::new(),
+            error: Some("Overlay is not paused".to_string()),
+            vanguard_blocked: false,
+            error_code: None,
+            failed_mods: Vec::new(),
+            timings: None,
+        };
+    }
+    
+    let launch = match OVERLAY_LAUNCH.lock().ok().and_then(|g| g.clone()) {
+        Some(launch) => launch,
+        None => {
+            return ActivationResult {
+                success: false,
+                message: String::new(),
+                error: Some("No previous overlay launch to resume".to_string()),
+                vanguard_blocked: false,
+                error_code: None,
+                failed_mods: Vec::new(),
+                timings: None,
+            };
+        }
+    };
+    
+    start_overlay_process(
+        &launch.mod_tools,
+        &launch.overlay_dir,
+        &launch.profile_dir,
+        &launch.game_path,
+        launch.mod_count,
+    )
+}
+
+// [COMMAND] Check if overlay is paused
+#[tauri::command]
+pub async fn is_overlay_paused() -> bool {
+    OVERLAY_PAUSED.load(Ordering::SeqCst)
+}
+
+// [COMMAND] Stop/deactivate overlay - bocchi-style graceful shutdown
+// NOTE: Does NOT delete any files - only stops the process
+#[tauri::command]
+pub async fn stop_overlay() -> ActivationResult {
+    println!("[MOD-STOP] Deactivating overlay...");
+    
+    let overlay_dir = get_overlay_directory();
+    
+    // [BOCCHI-STYLE] First try graceful shutdown via stdin
+    let graceful = shutdown_overlay_process();
+    OVERLAY_PAUSED.store(false, Ordering::SeqCst);
+    
+    // Force kill remaining mod-tools.exe processes - skipped when ours exited cleanly
     #[cfg(windows)]
-    {
+    if graceful != Some(true) {
         let mut cmd = Command::new("taskkill");
         cmd.args(&["/F", "/IM", "mod-tools.exe"]);
         cmd.creation_flags(CREATE_NO_WINDOW);
@@ -1511,21 +3172,34 @@ pub async fn stop_overlay() -> ActivationResult {
     
     // Update status file only - NO file deletion
     let status_file = overlay_dir.join("overlay.status");
-    std::fs::write(&status_file, "stopped").ok();
+    set_overlay_status(&status_file, "stopped", None, 0);
     
     println!("[MOD-STOP] Overlay stopped - all files preserved for instant restart");
     
     ActivationResult {
         success: true,
-        message: "Overlay deactivated".to_string(),
+        message: match graceful {
+            Some(true) => "Overlay deactivated (graceful shutdown)".to_string(),
+            Some(false) => "Overlay deactivated (forced shutdown)".to_string(),
+            None => "Overlay deactivated".to_string(),
+        },
         error: None,
         vanguard_blocked: false,
+        error_code: None,
+        failed_mods: Vec::new(),
+        timings: None,
     }
 }
 
 // [COMMAND] Check if overlay is currently ready/active
 #[tauri::command]
 pub async fn is_overlay_running() -> bool {
+    // [PAUSED] Paused overlay keeps its profile but is not injecting
+    if OVERLAY_PAUSED.load(Ordering::SeqCst) {
+        println!("[MOD-STATUS] Overlay is PAUSED");
+        return false;
+    }
+    
     // [PRIORITY] First check global process state
     if let Ok(guard) = OVERLAY_PROCESS.lock() {
         if let Some(ref process) = *guard {
@@ -1595,11 +3269,408 @@ pub async fn is_overlay_running() -> bool {
     false
 }
 
+// [FUNC] Check whether a mod-tools.exe process with the given PID is alive
+fn is_mod_tools_pid_alive(pid: u32) -> bool {
+    #[cfg(windows)]
+    {
+        let mut cmd = Command::new("tasklist");
+        cmd.args(["/FI", &format!("PID eq {}", pid), "/NH"]);
+        cmd.creation_flags(CREATE_NO_WINDOW);
+        
+        if let Ok(output) = cmd.output() {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            return stdout.contains("mod-tools.exe");
+        }
+        false
+    }
+    
+    #[cfg(not(windows))]
+    {
+        PathBuf::from(format!("/proc/{}", pid)).exists()
+    }
+}
+
+// [FUNC] Reset a stale "running" overlay.status left behind by an unclean shutdown
+// Returns true if the status file was rewritten
+pub fn reconcile_overlay_status() -> bool {
+    let overlay_dir = get_overlay_directory();
+    let status_file = overlay_dir.join("overlay.status");
+    
+    let status = match std::fs::read_to_string(&status_file) {
+        Ok(status) => status.trim().to_string(),
+        Err(_) => return false,
+    };
+    
+    if status != "running" {
+        return false;
+    }
+    
+    // A process held by this session is authoritative
+    if OVERLAY_PROCESS.lock().map(|g| g.is_some()).unwrap_or(false) {
+        return false;
+    }
+    
+    let recorded_pid = std::fs::read_to_string(overlay_dir.join("overlay.pid"))
+        .ok()
+        .and_then(|pid| pid.trim().parse::<u32>().ok());
+    
+    if let Some(pid) = recorded_pid {
+        if is_mod_tools_pid_alive(pid) {
+            println!("[MOD-STATUS] Recorded overlay PID {} still alive - status kept", pid);
+            return false;
+        }
+    }
+    
+    set_overlay_status(&status_file, "stopped", None, 0);
+    println!("[MOD-STATUS] Stale overlay status reset to stopped (recorded PID: {:?})", recorded_pid);
+    true
+}
+
+// [COMMAND] Reconcile overlay.status with the actual process state
+#[tauri::command]
+pub async fn reconcile_overlay_state() -> bool {
+    reconcile_overlay_status()
+}
+
+// [ENUM] Per-mod activation state
+#[derive(serde::Serialize, Clone, Copy, PartialEq)]
+pub enum ModStatus {
+    Installed,
+    InProfile,
+    Active,
+}
+
+// [STRUCT] Installed mod with its activation state
+#[derive(serde::Serialize)]
+pub struct ModState {
+    pub name: String,
+    pub state: ModStatus,
+    pub champion_id: Option<i32>,
+}
+
+// [STRUCT] Per-mod metadata stored next to the installed cache
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct InstalledManifestEntry {
+    pub champion_id: Option<i32>,
+}
+
+// [TYPE] Installed cache name -> manifest entry
+type InstalledManifest = std::collections::BTreeMap<String, InstalledManifestEntry>;
+
+// [FUNC] Get installed manifest file location
+fn get_installed_manifest_file() -> PathBuf {
+    get_overlay_directory().join("installed_manifest.json")
+}
+
+// [FUNC] Read installed manifest - empty if missing or corrupt
+fn load_installed_manifest() -> InstalledManifest {
+    std::fs::read_to_string(get_installed_manifest_file())
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+// [CONST] Installed folder prefixes - marketplace mods own "marketplace_", custom mods moved out of it get "custom_"
+const MARKETPLACE_PREFIX: &str = "marketplace_";
+const CUSTOM_PREFIX: &str = "custom_";
+
+// [FUNC] Keep custom mod names out of the marketplace namespace
+// A custom file named "marketplace_x.fantome" would otherwise share a folder with marketplace mod "x"
+fn namespace_custom_name(name: String) -> String {
+    if name.starts_with(MARKETPLACE_PREFIX) {
+        format!("{}{}", CUSTOM_PREFIX, name)
+    } else {
+        name
+    }
+}
+
+// [FUNC] Folder name of a mod whose source already lives directly in the installed cache
+// Such folders are reused as-is - renaming them would copy the mod into a second cache folder
+fn installed_folder_name(src_path: &Path, installed_dir: &Path) -> Option<String> {
+    if src_path.parent() != Some(installed_dir) || !src_path.is_dir() {
+        return None;
+    }
+    src_path.file_name().map(|name| name.to_string_lossy().to_string())
+}
+
+// [FUNC] Cache folder name used for a custom mod during import
+fn custom_mod_cache_name(mod_name: &str) -> String {
+    let sanitized_name = mod_name.split('.').next().unwrap_or(mod_name);
+    namespace_custom_name(sanitized_name
+        .chars()
+        .filter(|c| c.is_alphanumeric() || *c == '_' || *c == '-' || *c == ' ')
+        .collect::<String>()
+        .replace(' ', "_"))
+}
+
+// [STRUCT] Installed folder whose name is ambiguous between a custom and a marketplace mod
+#[derive(Serialize)]
+pub struct Collision {
+    pub folder: String,
+    pub reason: String,
+    pub renamed_to: Option<String>,
+}
+
+// [FUNC] Marketplace mod ids known locally - download cache folders plus the cached catalog
+fn known_marketplace_ids() -> std::collections::HashSet<String> {
+    let mut ids: std::collections::HashSet<String> = crate::marketplace::get_cached_mods()
+        .into_iter()
+        .map(|m| m.id)
+        .collect();
+    
+    if let Ok(entries) = std::fs::read_dir(crate::marketplace::get_marketplace_cache_dir()) {
+        ids.extend(entries
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().is_dir())
+            .map(|e| e.file_name().to_string_lossy().to_string()));
+    }
+    
+    ids
+}
+
+// [FUNC] Scan installed/ for folders that map to both a custom and a marketplace source
+fn scan_name_collisions(installed_dir: &Path) -> Vec<Collision> {
+    let folders: std::collections::HashSet<String> = std::fs::read_dir(installed_dir)
+        .map(|entries| entries
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().is_dir())
+            .map(|e| e.file_name().to_string_lossy().to_string())
+            .collect())
+        .unwrap_or_default();
+    
+    let marketplace_ids = known_marketplace_ids();
+    let mut collisions: Vec<Collision> = Vec::new();
+    
+    for folder in &folders {
+        let base = match folder.strip_prefix(MARKETPLACE_PREFIX) {
+            Some(base) if !base.is_empty() => base,
+            _ => continue,
+        };
+        
+        if folders.contains(base) {
+            collisions.push(Collision {
+                folder: folder.clone(),
+                reason: format!("Custom mod '{}' shares its base name", base),
+                renamed_to: None,
+            });
+        } else if !marketplace_ids.contains(base) {
+            collisions.push(Collision {
+                folder: folder.clone(),
+                reason: "Custom mod imported into the marketplace namespace".to_string(),
+                renamed_to: None,
+            });
+        }
+    }
+    
+    collisions.sort_by(|a, b| a.folder.cmp(&b.folder));
+    collisions
+}
+
+// [COMMAND] List installed folders whose names are ambiguous between custom and marketplace mods
+#[tauri::command]
+pub async fn detect_name_collisions() -> Vec<Collision> {
+    let collisions = scan_name_collisions(&get_overlay_directory().join("installed"));
+    println!("[MOD-CACHE] Name collisions found: {}", collisions.len());
+    collisions
+}
+
+// [COMMAND] Re-namespace custom mods that were imported under a marketplace_ name
+// Folders built into the active profile are left alone - stop the overlay first
+#[tauri::command]
+pub async fn repair_name_collisions() -> Vec<Collision> {
+    let overlay_dir = get_overlay_directory();
+    let installed_dir = overlay_dir.join("installed");
+    let profile_mods = read_profile_mods();
+    let marketplace_ids = known_marketplace_ids();
+    
+    let mut manifest = load_installed_manifest();
+    let mut manifest_changed = false;
+    let mut collisions = scan_name_collisions(&installed_dir);
+    
+    for collision in collisions.iter_mut() {
+        let base = collision.folder.strip_prefix(MARKETPLACE_PREFIX).unwrap_or(&collision.folder);
+        
+        // Genuine marketplace mod next to a same-named custom mod - custom delete now leaves it alone
+        if marketplace_ids.contains(base) {
+            continue;
+        }
+        if profile_mods.contains(&collision.folder) {
+            println!("[MOD-CACHE] Skipping active mod: {}", collision.folder);
+            continue;
+        }
+        
+        let target = namespace_custom_name(collision.folder.clone());
+        if installed_dir.join(&target).exists() {
+            println!("[MOD-CACHE] Skipping {} - {} already exists", collision.folder, target);
+            continue;
+        }
+        
+        match std::fs::rename(installed_dir.join(&collision.folder), installed_dir.join(&target)) {
+            Ok(()) => {
+                println!("[MOD-CACHE] Renamed {} -> {}", collision.folder, target);
+                if let Some(entry) = manifest.remove(&collision.folder) {
+                    manifest.insert(target.clone(), entry);
+                    manifest_changed = true;
+                }
+                collision.renamed_to = Some(target);
+            }
+            Err(e) => println!("[MOD-CACHE] WARN: Failed to rename {}: {}", collision.folder, e),
+        }
+    }
+    
+    if manifest_changed {
+        if let Ok(json) = serde_json::to_string_pretty(&manifest) {
+            let _ = std::fs::write(get_installed_manifest_file(), json);
+        }
+    }
+    
+    println!("[MOD-CACHE] Repaired {}/{} name collisions",
+             collisions.iter().filter(|c| c.renamed_to.is_some()).count(), collisions.len());
+    
+    collisions
+}
+
+// [FUNC] Champion of an installed mod - manual association first, then "{champion}_{skin}" folder names
+fn installed_mod_champion(name: &str, manifest: &InstalledManifest) -> Option<i32> {
+    manifest
+        .get(name)
+        .and_then(|entry| entry.champion_id)
+        .or_else(|| name.split('_').next().and_then(|prefix| prefix.parse::<i32>().ok()))
+}
+
+// [COMMAND] Associate a custom mod with a champion - shown in the installed listing
+#[tauri::command]
+pub async fn set_custom_mod_champion(mod_name: String, champion_id: i32) -> Result<(), String> {
+    if champion_id <= 0 {
+        return Err(format!("Invalid champion id: {}", champion_id));
+    }
+    if let Some(data) = crate::champion_data::load_champion_data() {
+        if !data.champions.contains_key(&champion_id) {
+            return Err(format!("Unknown champion id: {}", champion_id));
+        }
+    }
+    
+    let installed_dir = get_overlay_directory().join("installed");
+    let cache_name = custom_mod_cache_name(&mod_name);
+    let marketplace_name = format!("marketplace_{}", cache_name);
+    
+    // Marketplace mods are cached with a prefix - prefer whichever folder exists
+    let key = if !installed_dir.join(&cache_name).is_dir() && installed_dir.join(&marketplace_name).is_dir() {
+        marketplace_name
+    } else {
+        cache_name
+    };
+    
+    let mut manifest = load_installed_manifest();
+    manifest.entry(key.clone()).or_default().champion_id = Some(champion_id);
+    
+    let json = serde_json::to_string_pretty(&manifest)
+        .map_err(|e| format!("Failed to serialize manifest: {}", e))?;
+    std::fs::create_dir_all(get_overlay_directory()).ok();
+    std::fs::write(get_installed_manifest_file(), json)
+        .map_err(|e| format!("Failed to save manifest: {}", e))?;
+    
+    println!("[MOD-STATUS] {} associated with champion {}", key, champion_id);
+    Ok(())
+}
+
+// [FUNC] Read mod names built into the current profile by the last mkoverlay
+pub fn read_profile_mods() -> Vec<String> {
+    let profile_mods_file = get_overlay_directory().join("profile.mods");
+    std::fs::read_to_string(&profile_mods_file)
+        .map(|content| {
+            content
+                .lines()
+                .map(|l| l.trim().to_string())
+                .filter(|l| !l.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// [COMMAND] Report installed vs in-profile vs active state for each installed mod
+#[tauri::command]
+pub async fn get_mod_states() -> Vec<ModState> {
+    let installed_dir = get_overlay_directory().join("installed");
+    let profile_mods = read_profile_mods();
+    let manifest = load_installed_manifest();
+    let overlay_running = is_overlay_running().await;
+    
+    let mut states: Vec<ModState> = Vec::new();
+    
+    if let Ok(entries) = std::fs::read_dir(&installed_dir) {
+        for entry in entries.filter_map(|e| e.ok()) {
+            if !entry.path().is_dir() {
+                continue;
+            }
+            
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.starts_with("temp_") {
+                continue;
+            }
+            
+            let state = if profile_mods.contains(&name) {
+                if overlay_running { ModStatus::Active } else { ModStatus::InProfile }
+            } else {
+                ModStatus::Installed
+            };
+            
+            let champion_id = installed_mod_champion(&name, &manifest);
+            states.push(ModState { name, state, champion_id });
+        }
+    }
+    
+    states.sort_by(|a, b| a.name.cmp(&b.name));
+    
+    println!("[MOD-STATUS] Mod states: {} installed, {} in profile, overlay running: {}", 
+             states.len(), profile_mods.len(), overlay_running);
+    
+    states
+}
+
+// [FUNC] Remove temp_ folders left in installed/ by interrupted imports - returns count removed
+// Imports only create temp_ folders while an activation runs, so call this outside activations
+pub fn remove_temp_folders() -> u32 {
+    let installed_dir = get_overlay_directory().join("installed");
+    let mut cleaned = 0u32;
+    
+    if let Ok(entries) = std::fs::read_dir(&installed_dir) {
+        for entry in entries.filter_map(|e| e.ok()) {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if !name.starts_with("temp_") || !entry.path().is_dir() {
+                continue;
+            }
+            
+            match std::fs::remove_dir_all(entry.path()) {
+                Ok(()) => {
+                    println!("[MOD-CACHE] Cleaning temp folder: {}", name);
+                    cleaned += 1;
+                }
+                Err(e) => println!("[MOD-CACHE] Failed to remove temp folder {}: {}", name, e),
+            }
+        }
+    }
+    
+    cleaned
+}
+
+// [COMMAND] Clean leftover temp_ import folders without running a full activation
+#[tauri::command]
+pub async fn clean_temp_folders() -> u32 {
+    let cleaned = tauri::async_runtime::spawn_blocking(remove_temp_folders)
+        .await
+        .unwrap_or(0);
+    
+    println!("[MOD-CACHE] Removed {} leftover temp folders", cleaned);
+    cleaned
+}
+
 // [COMMAND] Delete custom mod cache - removes from mods/ and installed/ directories
 // Called when user deletes a custom mod from the UI
-// Always returns true - card deletion succeeds even if no cache files exist
+// Always succeeds - card deletion succeeds even if no cache files exist
+// was_active is set when the mod is part of the built profile so the UI can rebuild the overlay
 #[tauri::command]
-pub async fn delete_custom_mod_cache(mod_name: String) -> bool {
+pub async fn delete_custom_mod_cache(mod_name: String) -> CacheDeleteResult {
     println!("[MOD-CACHE] Deleting custom mod cache: {}", mod_name);
     
     let mods_dir = get_mods_directory();
@@ -1607,19 +3678,7 @@ pub async fn delete_custom_mod_cache(mod_name: String) -> bool {
     let installed_dir = overlay_dir.join("installed");
     
     // [SANITIZE] Generate the same cache name as used during import
-    let sanitized_name: String = if mod_name.contains('.') {
-        // Remove extension
-        let parts: Vec<&str> = mod_name.split('.').collect();
-        parts[0].to_string()
-    } else {
-        mod_name.clone()
-    };
-    
-    let cache_name: String = sanitized_name
-        .chars()
-        .filter(|c| c.is_alphanumeric() || *c == '_' || *c == '-' || *c == ' ')
-        .collect::<String>()
-        .replace(' ', "_");
+    let cache_name = custom_mod_cache_name(&mod_name);
     
     let mut deleted_count = 0;
     
@@ -1638,7 +3697,8 @@ pub async fn delete_custom_mod_cache(mod_name: String) -> bool {
     
     // [DELETE] From installed/ directory (extracted/imported files) - skip if not exists
     let installed_path = installed_dir.join(&cache_name);
-    if installed_path.exists() {
+    let custom_installed = installed_path.exists();
+    if custom_installed {
         if let Err(e) = std::fs::remove_dir_all(&installed_path) {
             println!("[MOD-CACHE] WARN: Failed to delete from installed/: {}", e);
         } else {
@@ -1652,8 +3712,12 @@ pub async fn delete_custom_mod_cache(mod_name: String) -> bool {
     // [MARKETPLACE-FIX] Also try marketplace_ prefixed name for marketplace mods
     // This handles cases where mod was imported from marketplace
     let marketplace_cache_name = format!("marketplace_{}", cache_name);
+    
+    let profile_mods = read_profile_mods();
+    let was_active = profile_mods.contains(&cache_name) || profile_mods.contains(&marketplace_cache_name);
+    // [COLLISION] Only when no custom folder matched - otherwise it belongs to a different marketplace mod
     let marketplace_installed_path = installed_dir.join(&marketplace_cache_name);
-    if marketplace_installed_path.exists() {
+    if !custom_installed && marketplace_installed_path.exists() {
         if let Err(e) = std::fs::remove_dir_all(&marketplace_installed_path) {
             println!("[MOD-CACHE] WARN: Failed to delete marketplace cache from installed/: {}", e);
         } else {
@@ -1671,9 +3735,429 @@ pub async fn delete_custom_mod_cache(mod_name: String) -> bool {
         }
     }
     
-    println!("[MOD-CACHE] Cache cleanup complete: {} items deleted", deleted_count);
+    println!("[MOD-CACHE] Cache cleanup complete: {} items deleted (was active: {})", deleted_count, was_active);
     
-    true
+    CacheDeleteResult { success: true, was_active }
+}
+
+// [COMMAND] Mods built into the active profile whose installed cache no longer exists
+#[tauri::command]
+pub async fn find_missing_active_mods() -> Vec<String> {
+    let installed_dir = get_overlay_directory().join("installed");
+    let missing: Vec<String> = read_profile_mods()
+        .into_iter()
+        .filter(|name| !installed_dir.join(name).is_dir())
+        .collect();
+    
+    if !missing.is_empty() {
+        println!("[MOD-STATUS] Active overlay references {} deleted mods: {:?}", missing.len(), missing);
+    }
+    
+    missing
+}
+
+// [STRUCT] Single step of the activation pipeline self-test
+#[derive(serde::Serialize)]
+pub struct PipelineStep {
+    pub name: String,
+    pub success: bool,
+    pub detail: String,
+}
+
+// [STRUCT] Activation pipeline self-test result
+#[derive(serde::Serialize)]
+pub struct PipelineTestResult {
+    pub success: bool,
+    pub steps: Vec<PipelineStep>,
+}
+
+// [FUNC] Get scratch directory for the activation pipeline self-test
+fn get_pipeline_test_directory() -> PathBuf {
+    let app_data = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
+    app_data.join("Wildflover").join("pipeline_test")
+}
+
+// [FUNC] Write a tiny .fantome test mod - META/info.json plus an empty v3 WAD
+fn write_test_mod(path: &Path) -> Result<(), String> {
+    use zip::write::SimpleFileOptions;
+    
+    let file = File::create(path).map_err(|e| format!("Failed to create test mod: {}", e))?;
+    let mut zip = zip::ZipWriter::new(file);
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+    
+    let info = serde_json::json!({
+        "Name": "Wildflover Pipeline Test",
+        "Author": "Wildflover",
+        "Version": "1.0.0",
+        "Description": "Activation pipeline self-test mod"
+    });
+    
+    // WAD v3 header: magic, version, signature, checksum, entry count (no entries)
+    let mut wad: Vec<u8> = vec![b'R', b'W', 3, 3];
+    wad.extend_from_slice(&[0u8; 256]);
+    wad.extend_from_slice(&[0u8; 8]);
+    wad.extend_from_slice(&0u32.to_le_bytes());
+    
+    zip.start_file("META/info.json", options).map_err(|e| e.to_string())?;
+    zip.write_all(info.to_string().as_bytes()).map_err(|e| e.to_string())?;
+    zip.start_file("WAD/PipelineTest.wad.client", options).map_err(|e| e.to_string())?;
+    zip.write_all(&wad).map_err(|e| e.to_string())?;
+    zip.finish().map_err(|e| format!("Failed to finalize test mod: {}", e))?;
+    
+    Ok(())
+}
+
+// [FUNC] Create a fake game folder with the files mod-tools looks for
+fn create_fake_game_directory(root: &Path) -> Result<PathBuf, String> {
+    let game_dir = root.join("Game");
+    std::fs::create_dir_all(game_dir.join("DATA").join("FINAL"))
+        .map_err(|e| format!("Failed to create fake game directory: {}", e))?;
+    
+    let exe_path = game_dir.join("League of Legends.exe");
+    if !exe_path.exists() {
+        std::fs::write(&exe_path, b"").map_err(|e| format!("Failed to create fake executable: {}", e))?;
+    }
+    
+    Ok(game_dir)
+}
+
+// [FUNC] Run a mod-tools subcommand and capture the outcome as a pipeline step
+fn run_pipeline_step(name: &str, mod_tools: &Path, args: &[&str]) -> PipelineStep {
+    let mut cmd = Command::new(mod_tools);
+    cmd.args(args);
+    
+    // [WINDOWS] Hide console window
+    #[cfg(windows)]
+    cmd.creation_flags(CREATE_NO_WINDOW);
+    
+    match cmd.output() {
+        Ok(output) => {
+            let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+            let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            let success = output.status.success();
+            let detail = if success {
+                format!("exit code {}", output.status.code().unwrap_or(0))
+            } else if !stderr.is_empty() {
+                stderr
+            } else {
+                stdout
+            };
+            PipelineStep { name: name.to_string(), success, detail }
+        }
+        Err(e) => PipelineStep {
+            name: name.to_string(),
+            success: false,
+            detail: format!("Failed to run mod-tools: {}", e),
+        },
+    }
+}
+
+// [COMMAND] Smoke test import/mkoverlay with a generated test mod - no game or injection needed
+// Uses an isolated scratch directory so the real overlay cache and profile are never touched
+#[tauri::command]
+pub async fn test_activation_pipeline(game_path: Option<String>) -> PipelineTestResult {
+    println!("[PIPELINE-TEST] Running activation pipeline self-test...");
+    
+    let mut steps: Vec<PipelineStep> = Vec::new();
+    let test_dir = get_pipeline_test_directory();
+    
+    // [STEP] Locate managers directory
+    let managers_dir = get_managers_directory();
+    steps.push(PipelineStep {
+        name: "managers".to_string(),
+        success: managers_dir.is_some(),
+        detail: managers_dir
+            .as_ref()
+            .map(|d| d.to_string_lossy().to_string())
+            .unwrap_or_else(|| "managers directory not found - mod-tools.exe missing".to_string()),
+    });
+    
+    let managers_dir = match managers_dir {
+        Some(dir) => dir,
+        None => return PipelineTestResult { success: false, steps },
+    };
+    
+    // [STEP] DLL presence (runoverlay dependency - reported, not required for import/mkoverlay)
+    let dll_check = verify_dll_exists(&managers_dir);
+    steps.push(PipelineStep {
+        name: "dll".to_string(),
+        success: dll_check.is_ok(),
+        detail: dll_check.err().unwrap_or_else(|| DLL_FILE_NAME.to_string()),
+    });
+    
+    // [STEP] Prepare scratch directories and test mod
+    let _ = std::fs::remove_dir_all(&test_dir);
+    let installed_dir = test_dir.join("installed");
+    let profile_dir = test_dir.join("profile");
+    std::fs::create_dir_all(&installed_dir).ok();
+    std::fs::create_dir_all(&profile_dir).ok();
+    
+    let test_mod = test_dir.join("pipeline_test.fantome");
+    let prepare = write_test_mod(&test_mod).and_then(|_| match game_path {
+        Some(ref path) if !path.trim().is_empty() => Ok(PathBuf::from(path)),
+        _ => create_fake_game_directory(&test_dir),
+    });
+    
+    let game_dir = match prepare {
+        Ok(dir) => {
+            steps.push(PipelineStep {
+                name: "prepare".to_string(),
+                success: true,
+                detail: format!("Game path: {}", dir.to_string_lossy()),
+            });
+            dir
+        }
+        Err(e) => {
+            steps.push(PipelineStep { name: "prepare".to_string(), success: false, detail: e });
+            return PipelineTestResult { success: false, steps };
+        }
+    };
+    
+    let mod_tools = managers_dir.join("mod-tools.exe");
+    let game_arg = format!("--game:{}", game_dir.to_string_lossy());
+    let target_dir = installed_dir.join("pipeline_test");
+    
+    // [STEP] Import
+    let import_step = run_pipeline_step("import", &mod_tools, &[
+        "import",
+        test_mod.to_str().unwrap_or(""),
+        target_dir.to_str().unwrap_or(""),
+        &game_arg,
+    ]);
+    let import_ok = import_step.success;
+    steps.push(import_step);
+    
+    // [STEP] mkoverlay - only meaningful if import produced a mod folder
+    if import_ok {
+        let mkoverlay_step = run_pipeline_step("mkoverlay", &mod_tools, &[
+            "mkoverlay",
+            installed_dir.to_str().unwrap_or(""),
+            profile_dir.to_str().unwrap_or(""),
+            &game_arg,
+            "--mods:pipeline_test",
+            "--noTFT",
+            "--ignoreConflict",
+        ]);
+        steps.push(mkoverlay_step);
+    }
+    
+    // [CLEANUP] Scratch output is not needed after the test
+    let _ = std::fs::remove_dir_all(&test_dir);
+    
+    let success = steps.iter().filter(|s| s.name != "dll").all(|s| s.success)
+        && steps.iter().any(|s| s.name == "mkoverlay");
+    
+    for step in &steps {
+        println!("[PIPELINE-TEST]   {}: {} - {}", step.name, if step.success { "OK" } else { "FAILED" }, step.detail);
+    }
+    println!("[PIPELINE-TEST] Result: {}", if success { "PASSED" } else { "FAILED" });
+    
+    PipelineTestResult { success, steps }
+}
+
+// [STRUCT] Compact version block for bug reports
+#[derive(serde::Serialize)]
+pub struct VersionInfo {
+    pub app_version: String,
+    pub mod_tools_version: Option<String>,
+    pub dll_size: Option<u64>,
+    pub dll_hash: Option<String>,
+    pub champion_data_version: Option<String>,
+}
+
+// [COMMAND] Get app, mod-tools, DLL and champion-data versions in one call
+#[tauri::command]
+pub async fn get_version_info() -> VersionInfo {
+    let managers_dir = get_managers_directory();
+    
+    let mod_tools_version = managers_dir
+        .as_ref()
+        .and_then(|d| crate::game_version::read_file_version(&d.join("mod-tools.exe")));
+    
+    let dll_path = managers_dir.as_ref().map(|d| d.join(DLL_FILE_NAME)).filter(|p| p.exists());
+    let dll_size = dll_path.as_ref().and_then(|p| std::fs::metadata(p).ok()).map(|m| m.len());
+    let dll_hash = dll_path.as_ref().and_then(|p| crate::file_hash::hash_file(p).ok());
+    
+    let info = VersionInfo {
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        mod_tools_version,
+        dll_size,
+        dll_hash,
+        champion_data_version: crate::champion_data::champion_data_version(),
+    };
+    
+    println!("[VERSION-INFO] app {} | mod-tools {:?} | dll {:?} bytes ({:?}) | champion-data {:?}",
+             info.app_version, info.mod_tools_version, info.dll_size, info.dll_hash, info.champion_data_version);
+    
+    info
+}
+
+// [CONST] cslol toolchain shipped in the managers folder - (file name, required for activation)
+const MANAGER_COMPONENTS: [(&str, bool); 6] = [
+    ("mod-tools.exe", true),
+    (DLL_FILE_NAME, true),
+    ("cslol-diag.exe", false),
+    ("wad-extract.exe", false),
+    ("wad-make.exe", false),
+    ("hashes.game.txt", false),
+];
+
+// [STRUCT] Presence and size of a single managers component
+#[derive(serde::Serialize)]
+pub struct ManagerComponent {
+    pub name: String,
+    pub required: bool,
+    pub present: bool,
+    pub size: Option<u64>,
+}
+
+// [STRUCT] Managers folder pre-flight result
+#[derive(serde::Serialize)]
+pub struct ManagersCheck {
+    pub ready: bool,
+    pub managers_dir: Option<String>,
+    pub components: Vec<ManagerComponent>,
+    pub missing: Vec<String>,
+}
+
+// [COMMAND] Verify the managers folder holds a complete cslol toolchain
+// Empty files count as missing - a truncated extract is as broken as an absent one
+#[tauri::command]
+pub async fn verify_managers_complete() -> ManagersCheck {
+    let managers_dir = get_managers_directory();
+    
+    let components: Vec<ManagerComponent> = MANAGER_COMPONENTS
+        .iter()
+        .map(|(name, required)| {
+            let size = managers_dir
+                .as_ref()
+                .and_then(|d| std::fs::metadata(d.join(name)).ok())
+                .filter(|m| m.is_file())
+                .map(|m| m.len());
+            ManagerComponent {
+                name: name.to_string(),
+                required: *required,
+                present: size.map(|s| s > 0).unwrap_or(false),
+                size,
+            }
+        })
+        .collect();
+    
+    let missing: Vec<String> = components
+        .iter()
+        .filter(|c| !c.present)
+        .map(|c| c.name.clone())
+        .collect();
+    
+    let ready = managers_dir.is_some() && components.iter().all(|c| c.present || !c.required);
+    
+    println!("[MANAGERS-CHECK] ready: {} | dir: {:?} | missing: {:?}", ready, managers_dir, missing);
+    
+    ManagersCheck {
+        ready,
+        managers_dir: managers_dir.map(|p| p.to_string_lossy().to_string()),
+        components,
+        missing,
+    }
+}
+
+// [STRUCT] Installed vs bundled copy of one managers file
+#[derive(serde::Serialize)]
+pub struct ManagerFileVersion {
+    pub name: String,
+    pub bundled_hash: Option<String>,
+    pub installed_hash: Option<String>,
+    pub up_to_date: bool,
+}
+
+// [STRUCT] Managers folder compared with the toolchain shipped in the app bundle
+#[derive(serde::Serialize)]
+pub struct ManagersVersionStatus {
+    pub bundled_dir: Option<String>,
+    pub managers_dir: Option<String>,
+    pub files: Vec<ManagerFileVersion>,
+    pub needs_update: bool,
+}
+
+// [FUNC] managers folder shipped as a bundle resource - None in dev builds without resources
+fn get_bundled_managers_directory(app: &tauri::AppHandle) -> Option<PathBuf> {
+    use tauri::Manager;
+    app.path()
+        .resource_dir()
+        .ok()
+        .map(|dir| dir.join("managers"))
+        .filter(|dir| dir.join("mod-tools.exe").exists())
+}
+
+// [FUNC] Hash every bundled component against the managers folder in use
+fn compare_managers(bundled_dir: Option<&Path>, managers_dir: Option<&Path>) -> ManagersVersionStatus {
+    let hash_of = |dir: Option<&Path>, name: &str| {
+        dir.map(|d| d.join(name))
+            .filter(|p| p.is_file())
+            .and_then(|p| crate::file_hash::hash_file(&p).ok())
+    };
+    
+    let files: Vec<ManagerFileVersion> = MANAGER_COMPONENTS
+        .iter()
+        .filter_map(|(name, _)| {
+            // Only files the bundle actually ships can be compared or restored
+            let bundled_hash = hash_of(bundled_dir, name)?;
+            let installed_hash = hash_of(managers_dir, name);
+            Some(ManagerFileVersion {
+                name: name.to_string(),
+                up_to_date: installed_hash.as_deref() == Some(bundled_hash.as_str()),
+                bundled_hash: Some(bundled_hash),
+                installed_hash,
+            })
+        })
+        .collect();
+    
+    ManagersVersionStatus {
+        bundled_dir: bundled_dir.map(|p| p.to_string_lossy().to_string()),
+        managers_dir: managers_dir.map(|p| p.to_string_lossy().to_string()),
+        needs_update: files.iter().any(|f| !f.up_to_date),
+        files,
+    }
+}
+
+// [COMMAND] Compare managers tools with the bundled known-good versions
+#[tauri::command]
+pub async fn check_managers_version(app: tauri::AppHandle) -> ManagersVersionStatus {
+    let bundled_dir = get_bundled_managers_directory(&app);
+    let managers_dir = get_managers_directory();
+    
+    let status = compare_managers(bundled_dir.as_deref(), managers_dir.as_deref());
+    
+    println!("[MANAGERS-VERSION] needs update: {} | bundled: {:?} | in use: {:?}",
+             status.needs_update, status.bundled_dir, status.managers_dir);
+    
+    status
+}
+
+// [COMMAND] Copy bundled tools over stale managers files - overlay must be stopped (DLL is locked while injecting)
+#[tauri::command]
+pub async fn update_managers_from_bundle(app: tauri::AppHandle) -> Result<ManagersVersionStatus, String> {
+    let bundled_dir = get_bundled_managers_directory(&app)
+        .ok_or_else(|| "No bundled managers found in app resources".to_string())?;
+    let managers_dir = get_managers_directory()
+        .unwrap_or_else(|| bundled_dir.clone());
+    
+    if managers_dir == bundled_dir {
+        return Ok(compare_managers(Some(&bundled_dir), Some(&managers_dir)));
+    }
+    
+    if OVERLAY_PROCESS.lock().map(|g| g.is_some()).unwrap_or(false) {
+        return Err("Stop the overlay before updating managers".to_string());
+    }
+    
+    let before = compare_managers(Some(&bundled_dir), Some(&managers_dir));
+    for file in before.files.iter().filter(|f| !f.up_to_date) {
+        std::fs::copy(bundled_dir.join(&file.name), managers_dir.join(&file.name))
+            .map_err(|e| format!("Failed to update {}: {}", file.name, e))?;
+        println!("[MANAGERS-VERSION] Updated {} from bundle", file.name);
+    }
+    
+    Ok(compare_managers(Some(&bundled_dir), Some(&managers_dir)))
 }
 
 // [DIAGNOSTIC] System diagnostic information for troubleshooting
@@ -1690,6 +4174,7 @@ pub struct SystemDiagnostic {
     pub profile_dir_exists: bool,
     pub profile_file_count: usize,
     pub installed_mod_count: usize,
+    pub overlay_stdin_alive: bool,
 }
 
 // [COMMAND] Run system diagnostic - helps identify why mods aren't working
@@ -1757,6 +4242,7 @@ pub async fn run_diagnostic() -> SystemDiagnostic {
         profile_dir_exists,
         profile_file_count,
         installed_mod_count,
+        overlay_stdin_alive: overlay_stdin_alive(),
     };
     
     println!("[DIAGNOSTIC] Results:");
@@ -1768,6 +4254,36 @@ pub async fn run_diagnostic() -> SystemDiagnostic {
     println!("[DIAGNOSTIC]   cslol_version: {:?}", diagnostic.cslol_version);
     println!("[DIAGNOSTIC]   profile_files: {}", diagnostic.profile_file_count);
     println!("[DIAGNOSTIC]   installed_mods: {}", diagnostic.installed_mod_count);
+    println!("[DIAGNOSTIC]   overlay_stdin_alive: {}", diagnostic.overlay_stdin_alive);
     
     diagnostic
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_reuses_installed_marketplace_folder() {
+        let installed_dir = std::env::temp_dir().join(format!("wildflover_installed_{}", std::process::id()));
+        let folder = installed_dir.join("marketplace_abc123");
+        std::fs::create_dir_all(folder.join("WAD")).unwrap();
+
+        // retry_failed_mods passes profile.mods entries as installed/<name>
+        let name = installed_folder_name(&folder, &installed_dir);
+        let entries = std::fs::read_dir(&installed_dir).unwrap().count();
+        std::fs::remove_dir_all(&installed_dir).ok();
+
+        assert_eq!(name.as_deref(), Some("marketplace_abc123"));
+        assert_eq!(installed_dir.join(name.unwrap()), folder);
+        assert_eq!(entries, 1);
+    }
+
+    #[test]
+    fn custom_files_outside_installed_are_namespaced() {
+        let installed_dir = std::env::temp_dir().join("wildflover_installed_missing");
+        let src = PathBuf::from("/mods/marketplace_skin.fantome");
+        assert_eq!(installed_folder_name(&src, &installed_dir), None);
+        assert_eq!(custom_mod_cache_name("marketplace_skin.fantome"), "custom_marketplace_skin");
+    }
+}
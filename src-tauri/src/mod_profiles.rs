@@ -0,0 +1,94 @@
+//! File: mod_profiles.rs
+//! Author: Wildflover
+//! Description: Named mod selections (e.g. "ranked loadout") for one-click activation
+//!              - One JSON file per profile under Wildflover/profiles/
+//!              - Loading drops mods whose files are gone and reports them
+//! Language: Rust
+
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use crate::mod_manager::ModItem;
+
+// [STRUCT] Profile contents ready for activate_mods
+#[derive(Serialize)]
+pub struct LoadedProfile {
+    pub mods: Vec<ModItem>,
+    pub dropped: Vec<String>,
+}
+
+// [FUNC] Get profiles directory location
+fn get_profiles_directory() -> PathBuf {
+    let app_data = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
+    app_data.join("Wildflover").join("profiles")
+}
+
+// [FUNC] Profile file for a name - rejects names that are not safe file names
+fn profile_file(name: &str) -> Result<PathBuf, String> {
+    let name = name.trim();
+    let valid = !name.is_empty()
+        && name.len() <= 64
+        && name.chars().all(|c| c.is_alphanumeric() || c == ' ' || c == '-' || c == '_');
+    if !valid {
+        return Err("Profile name may only contain letters, numbers, spaces, - and _".to_string());
+    }
+    Ok(get_profiles_directory().join(format!("{}.json", name)))
+}
+
+// [COMMAND] Save the current mod selection under a name - overwrites an existing profile
+#[tauri::command]
+pub async fn save_profile(name: String, mods: Vec<ModItem>) -> Result<(), String> {
+    let file = profile_file(&name)?;
+    std::fs::create_dir_all(get_profiles_directory())
+        .map_err(|e| format!("Failed to create profiles directory: {}", e))?;
+
+    let json = serde_json::to_string_pretty(&mods)
+        .map_err(|e| format!("Failed to serialize profile: {}", e))?;
+    std::fs::write(&file, json).map_err(|e| format!("Failed to save profile: {}", e))?;
+
+    println!("[MOD-PROFILES] Saved '{}' ({} mods)", name.trim(), mods.len());
+    Ok(())
+}
+
+// [COMMAND] Load a profile - mods whose path no longer exists are dropped and listed
+#[tauri::command]
+pub async fn load_profile(name: String) -> Result<LoadedProfile, String> {
+    let file = profile_file(&name)?;
+    let content = std::fs::read_to_string(&file)
+        .map_err(|_| format!("Profile not found: {}", name.trim()))?;
+    let saved: Vec<ModItem> = serde_json::from_str(&content)
+        .map_err(|e| format!("Invalid profile file: {}", e))?;
+
+    let (mods, missing): (Vec<ModItem>, Vec<ModItem>) = saved
+        .into_iter()
+        .partition(|m| Path::new(&m.path).exists());
+    let dropped: Vec<String> = missing.into_iter().map(|m| m.name).collect();
+
+    if !dropped.is_empty() {
+        println!("[MOD-PROFILES] '{}': dropped missing mods {:?}", name.trim(), dropped);
+    }
+    println!("[MOD-PROFILES] Loaded '{}' ({} mods)", name.trim(), mods.len());
+
+    Ok(LoadedProfile { mods, dropped })
+}
+
+// [COMMAND] Names of saved profiles, sorted
+#[tauri::command]
+pub async fn list_profiles() -> Vec<String> {
+    let mut names: Vec<String> = std::fs::read_dir(get_profiles_directory())
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .filter_map(|e| {
+                    let path = e.path();
+                    if path.extension().and_then(|x| x.to_str()) != Some("json") {
+                        return None;
+                    }
+                    path.file_stem().map(|s| s.to_string_lossy().to_string())
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    names.sort_by_key(|n| n.to_lowercase());
+    names
+}
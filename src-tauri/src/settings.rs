@@ -0,0 +1,351 @@
+//! File: settings.rs
+//! Author: Wildflover
+//! Description: Persistent backend settings stored in settings.json
+//!              - Serde defaults so older files keep loading as fields are added
+//!              - Import concurrency limit for mod activation
+//!              - Global concurrent download limit
+//!              - Skin repository location (owner/repo/branch/subpath)
+//!              - Scheduled cache cleanup limits
+//!              - User-Agent sent with marketplace GitHub requests
+//!              - mkoverlay retry attempts and delay
+//!              - Overlay spawn verification delay
+//!              - Strict download size verification against the catalog
+//!              - Skin archive extraction filters (locales, TFT, crash-prone assets)
+//! Language: Rust
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+// [CONST] Import concurrency bounds and default
+pub const MIN_IMPORT_CONCURRENCY: u32 = 1;
+pub const MAX_IMPORT_CONCURRENCY: u32 = 16;
+const DEFAULT_IMPORT_CONCURRENCY: u32 = 4;
+
+// [CONST] Concurrent download bounds and default - keeps GitHub abuse limits at bay
+pub const MIN_CONCURRENT_DOWNLOADS: u32 = 1;
+pub const MAX_CONCURRENT_DOWNLOADS: u32 = 32;
+const DEFAULT_CONCURRENT_DOWNLOADS: u32 = 6;
+
+// [CONST] Default skin repository location
+const DEFAULT_SKIN_REPO_OWNER: &str = "Alban1911";
+const DEFAULT_SKIN_REPO_NAME: &str = "LeagueSkins";
+const DEFAULT_SKIN_REPO_BRANCH: &str = "main";
+const DEFAULT_SKIN_REPO_SUBPATH: &str = "skins";
+
+// [CONST] Default User-Agent for marketplace GitHub requests
+pub const DEFAULT_USER_AGENT: &str = "Wildflover-Marketplace";
+
+// [CONST] mkoverlay retry bounds and defaults - Vanguard timing varies per machine
+pub const MIN_MKOVERLAY_ATTEMPTS: u32 = 1;
+pub const MAX_MKOVERLAY_ATTEMPTS: u32 = 10;
+pub const MAX_MKOVERLAY_RETRY_DELAY_MS: u64 = 10_000;
+const DEFAULT_MKOVERLAY_ATTEMPTS: u32 = 3;
+const DEFAULT_MKOVERLAY_RETRY_DELAY_MS: u64 = 500;
+
+// [CONST] Overlay spawn verification delay bounds and default
+pub const MIN_OVERLAY_VERIFY_DELAY_MS: u64 = 100;
+pub const MAX_OVERLAY_VERIFY_DELAY_MS: u64 = 10_000;
+const DEFAULT_OVERLAY_VERIFY_DELAY_MS: u64 = 500;
+
+// [CONST] Locale WADs skipped during extraction by default - these cause game crashes
+const DEFAULT_SKIP_LOCALES: [&str; 27] = [
+    "tr_TR", "en_US", "en_GB", "de_DE", "es_ES", "es_MX",
+    "fr_FR", "it_IT", "pl_PL", "pt_BR", "ro_RO", "ru_RU",
+    "el_GR", "cs_CZ", "hu_HU", "ja_JP", "ko_KR", "zh_CN",
+    "zh_TW", "th_TH", "vi_VN", "ar_AE", "id_ID", "ms_MY",
+    "ph_PH", "sg_SG", "tw_TW",
+];
+
+// [STATE] Serializes read-modify-write of settings.json
+static SETTINGS_LOCK: Mutex<()> = Mutex::new(());
+
+// [STRUCT] Backend settings
+#[derive(Serialize, Deserialize, Clone)]
+pub struct AppSettings {
+    #[serde(default = "default_import_concurrency")]
+    pub import_concurrency: u32,
+    #[serde(default = "default_concurrent_downloads")]
+    pub max_concurrent_downloads: u32,
+    #[serde(default)]
+    pub skin_repo: SkinRepoConfig,
+    #[serde(default)]
+    pub auto_cache_cleanup: Option<AutoCacheCleanup>,
+    #[serde(default = "default_user_agent")]
+    pub user_agent: String,
+    #[serde(default)]
+    pub mkoverlay_retries: MkoverlayRetries,
+    #[serde(default = "default_overlay_verify_delay")]
+    pub overlay_verify_delay_ms: u64,
+    #[serde(default = "default_strict_size_verification")]
+    pub strict_size_verification: bool,
+    #[serde(default)]
+    pub extraction_filters: ExtractionFilters,
+    #[serde(default)]
+    pub rpc_enabled: bool,
+}
+
+// [STRUCT] Which archive entries extract_zip leaves out
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ExtractionFilters {
+    pub skip_locales: Vec<String>,
+    pub skip_tft: bool,
+    pub skip_crash_prone: bool,
+}
+
+impl Default for ExtractionFilters {
+    fn default() -> Self {
+        ExtractionFilters {
+            skip_locales: DEFAULT_SKIP_LOCALES.iter().map(|l| l.to_string()).collect(),
+            skip_tft: true,
+            skip_crash_prone: true,
+        }
+    }
+}
+
+// [STRUCT] How often activation retries a failed mkoverlay run
+#[derive(Serialize, Deserialize, Clone)]
+pub struct MkoverlayRetries {
+    pub attempts: u32,
+    pub delay_ms: u64,
+}
+
+impl Default for MkoverlayRetries {
+    fn default() -> Self {
+        MkoverlayRetries {
+            attempts: DEFAULT_MKOVERLAY_ATTEMPTS,
+            delay_ms: DEFAULT_MKOVERLAY_RETRY_DELAY_MS,
+        }
+    }
+}
+
+impl MkoverlayRetries {
+    // [FUNC] Same values with attempts and delay kept within bounds
+    pub fn clamped(&self) -> Self {
+        MkoverlayRetries {
+            attempts: self.attempts.clamp(MIN_MKOVERLAY_ATTEMPTS, MAX_MKOVERLAY_ATTEMPTS),
+            delay_ms: self.delay_ms.min(MAX_MKOVERLAY_RETRY_DELAY_MS),
+        }
+    }
+}
+
+// [STRUCT] mkoverlay retry settings under the overlay naming - stored as MkoverlayRetries
+#[derive(Serialize, Deserialize, Clone)]
+pub struct OverlayRetryConfig {
+    pub max_attempts: u32,
+    pub delay_ms: u64,
+}
+
+impl From<MkoverlayRetries> for OverlayRetryConfig {
+    fn from(retries: MkoverlayRetries) -> Self {
+        OverlayRetryConfig {
+            max_attempts: retries.attempts,
+            delay_ms: retries.delay_ms,
+        }
+    }
+}
+
+// [STRUCT] Scheduled cache cleanup - keep total cache under max_bytes
+#[derive(Serialize, Deserialize, Clone)]
+pub struct AutoCacheCleanup {
+    pub max_bytes: u64,
+    pub interval_hours: u64,
+}
+
+// [STRUCT] GitHub location skins are downloaded from
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SkinRepoConfig {
+    pub owner: String,
+    pub repo: String,
+    pub branch: String,
+    pub subpath: String,
+}
+
+impl Default for SkinRepoConfig {
+    fn default() -> Self {
+        SkinRepoConfig {
+            owner: DEFAULT_SKIN_REPO_OWNER.to_string(),
+            repo: DEFAULT_SKIN_REPO_NAME.to_string(),
+            branch: DEFAULT_SKIN_REPO_BRANCH.to_string(),
+            subpath: DEFAULT_SKIN_REPO_SUBPATH.to_string(),
+        }
+    }
+}
+
+fn default_import_concurrency() -> u32 {
+    DEFAULT_IMPORT_CONCURRENCY
+}
+
+fn default_concurrent_downloads() -> u32 {
+    DEFAULT_CONCURRENT_DOWNLOADS
+}
+
+fn default_user_agent() -> String {
+    DEFAULT_USER_AGENT.to_string()
+}
+
+fn default_overlay_verify_delay() -> u64 {
+    DEFAULT_OVERLAY_VERIFY_DELAY_MS
+}
+
+fn default_strict_size_verification() -> bool {
+    true
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        AppSettings {
+            import_concurrency: DEFAULT_IMPORT_CONCURRENCY,
+            max_concurrent_downloads: DEFAULT_CONCURRENT_DOWNLOADS,
+            skin_repo: SkinRepoConfig::default(),
+            auto_cache_cleanup: None,
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            mkoverlay_retries: MkoverlayRetries::default(),
+            overlay_verify_delay_ms: DEFAULT_OVERLAY_VERIFY_DELAY_MS,
+            strict_size_verification: true,
+            extraction_filters: ExtractionFilters::default(),
+            rpc_enabled: false,
+        }
+    }
+}
+
+// [FUNC] Get settings file location
+fn get_settings_file() -> PathBuf {
+    let app_data = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
+    app_data.join("Wildflover").join("settings.json")
+}
+
+// [FUNC] Read settings from disk without locking
+fn read_settings() -> AppSettings {
+    std::fs::read_to_string(get_settings_file())
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+// [FUNC] Load settings - falls back to defaults if missing or corrupt
+pub fn load_settings() -> AppSettings {
+    let _guard = SETTINGS_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    read_settings()
+}
+
+// [FUNC] Apply a change to settings and persist it
+pub fn update_settings<F: FnOnce(&mut AppSettings)>(change: F) -> Result<AppSettings, String> {
+    let _guard = SETTINGS_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+    let mut settings = read_settings();
+    change(&mut settings);
+
+    let settings_file = get_settings_file();
+    if let Some(parent) = settings_file.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| format!("Failed to create settings directory: {}", e))?;
+    }
+
+    let json = serde_json::to_string_pretty(&settings)
+        .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+    std::fs::write(&settings_file, json).map_err(|e| format!("Failed to save settings: {}", e))?;
+
+    Ok(settings)
+}
+
+// [COMMAND] Get concurrent import limit used by activate_mods
+#[tauri::command]
+pub async fn get_import_concurrency() -> u32 {
+    load_settings()
+        .import_concurrency
+        .clamp(MIN_IMPORT_CONCURRENCY, MAX_IMPORT_CONCURRENCY)
+}
+
+// [COMMAND] Set concurrent import limit - clamped to 1-16, returns the stored value
+#[tauri::command]
+pub async fn set_import_concurrency(n: u32) -> Result<u32, String> {
+    let value = n.clamp(MIN_IMPORT_CONCURRENCY, MAX_IMPORT_CONCURRENCY);
+    update_settings(|s| s.import_concurrency = value)?;
+
+    println!("[SETTINGS] Import concurrency set to {}", value);
+    Ok(value)
+}
+
+// [COMMAND] Get mkoverlay retry attempts and delay used by activate_mods (defaults 3 / 500ms)
+#[tauri::command]
+pub async fn get_mkoverlay_retries() -> MkoverlayRetries {
+    load_settings().mkoverlay_retries.clamped()
+}
+
+// [COMMAND] Set mkoverlay retry attempts and delay - clamped, returns the stored values
+#[tauri::command]
+pub async fn set_mkoverlay_retries(attempts: u32, delay_ms: u64) -> Result<MkoverlayRetries, String> {
+    let value = MkoverlayRetries { attempts, delay_ms }.clamped();
+    let stored = value.clone();
+    update_settings(|s| s.mkoverlay_retries = stored)?;
+
+    println!("[SETTINGS] mkoverlay retries set to {} attempts, {}ms delay", value.attempts, value.delay_ms);
+    Ok(value)
+}
+
+// [COMMAND] Get the overlay retry config - same values as get_mkoverlay_retries
+#[tauri::command]
+pub async fn get_overlay_retry_config() -> OverlayRetryConfig {
+    get_mkoverlay_retries().await.into()
+}
+
+// [COMMAND] Set the overlay retry config - thin wrapper over set_mkoverlay_retries
+#[tauri::command]
+pub async fn set_overlay_retry_config(config: OverlayRetryConfig) -> Result<OverlayRetryConfig, String> {
+    set_mkoverlay_retries(config.max_attempts, config.delay_ms).await.map(Into::into)
+}
+
+// [COMMAND] Get how long runoverlay is watched for an immediate exit before activation counts as started
+#[tauri::command]
+pub async fn get_overlay_verify_delay() -> u64 {
+    load_settings()
+        .overlay_verify_delay_ms
+        .clamp(MIN_OVERLAY_VERIFY_DELAY_MS, MAX_OVERLAY_VERIFY_DELAY_MS)
+}
+
+// [COMMAND] Set overlay spawn verification delay - clamped to 100-10000ms, returns the stored value
+#[tauri::command]
+pub async fn set_overlay_verify_delay(ms: u64) -> Result<u64, String> {
+    let value = ms.clamp(MIN_OVERLAY_VERIFY_DELAY_MS, MAX_OVERLAY_VERIFY_DELAY_MS);
+    update_settings(|s| s.overlay_verify_delay_ms = value)?;
+
+    println!("[SETTINGS] Overlay verify delay set to {}ms", value);
+    Ok(value)
+}
+
+// [COMMAND] Toggle marketplace download size check against catalog fileSize - returns the stored value
+// Off lets users download mods whose legacy catalog entry carries a wrong size
+#[tauri::command]
+pub async fn set_strict_size_verification(enabled: bool) -> Result<bool, String> {
+    update_settings(|s| s.strict_size_verification = enabled)?;
+
+    println!("[SETTINGS] Strict size verification {}", if enabled { "enabled" } else { "disabled" });
+    Ok(enabled)
+}
+
+// [COMMAND] Get extraction filters applied to downloaded skin archives
+#[tauri::command]
+pub async fn get_extraction_filters() -> ExtractionFilters {
+    load_settings().extraction_filters
+}
+
+// [COMMAND] Set extraction filters - locale codes like "ko_KR", returns the stored value
+// Only affects skins extracted after the change; cached skins keep their files
+#[tauri::command]
+pub async fn set_extraction_filters(filters: ExtractionFilters) -> Result<ExtractionFilters, String> {
+    let mut skip_locales: Vec<String> = Vec::new();
+    for locale in filters.skip_locales {
+        let locale = locale.trim().trim_matches('.').to_string();
+        if !locale.is_empty() && !skip_locales.contains(&locale) {
+            skip_locales.push(locale);
+        }
+    }
+
+    let value = ExtractionFilters { skip_locales, ..filters };
+    let stored = value.clone();
+    update_settings(|s| s.extraction_filters = stored)?;
+
+    println!("[SETTINGS] Extraction filters: {} locales skipped, TFT {}, crash-prone {}",
+             value.skip_locales.len(), value.skip_tft, value.skip_crash_prone);
+    Ok(value)
+}
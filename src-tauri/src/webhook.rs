@@ -3,15 +3,117 @@
 //! Description: Discord webhook notification service
 //!              - Login success notifications
 //!              - User info embed messages
+//!              - User-configured webhook URL, validated before saving
 //! Language: Rust
 
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 
 // [CONSTANTS] Discord webhook URL
 // IMPORTANT: Replace with your own Discord webhook URL
 // Create one at: Discord Server Settings > Integrations > Webhooks
 const LOGIN_WEBHOOK_URL: &str = "YOUR_DISCORD_WEBHOOK_URL";
 
+// [CONST] Accepted Discord webhook URL prefixes
+const WEBHOOK_PREFIXES: [&str; 2] = [
+    "https://discord.com/api/webhooks/",
+    "https://discordapp.com/api/webhooks/",
+];
+
+// [STRUCT] User-configured webhook - kept out of settings.json so config exports never carry the token
+#[derive(Serialize, Deserialize, Default)]
+struct WebhookConfig {
+    url: Option<String>,
+}
+
+// [FUNC] Get webhook config file location
+fn get_webhook_config_file() -> PathBuf {
+    let app_data = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
+    app_data.join("Wildflover").join("webhook.json")
+}
+
+// [FUNC] Webhook URL in use - user-configured one wins over the built-in constant
+fn webhook_url() -> String {
+    std::fs::read_to_string(get_webhook_config_file())
+        .ok()
+        .and_then(|content| serde_json::from_str::<WebhookConfig>(&content).ok())
+        .and_then(|config| config.url)
+        .unwrap_or_else(|| LOGIN_WEBHOOK_URL.to_string())
+}
+
+// [FUNC] False while the webhook URL is still the shipped placeholder
+pub fn webhook_configured() -> bool {
+    webhook_url().starts_with("https://")
+}
+
+// [FUNC] Check a URL has the https://discord.com/api/webhooks/{id}/{token} shape
+fn validate_webhook_url(url: &str) -> Result<(), String> {
+    let rest = WEBHOOK_PREFIXES
+        .iter()
+        .find_map(|prefix| url.strip_prefix(prefix))
+        .ok_or_else(|| "Webhook URL must start with https://discord.com/api/webhooks/".to_string())?;
+
+    let mut parts = rest.trim_end_matches('/').split('/');
+    let id = parts.next().unwrap_or("");
+    let token = parts.next().unwrap_or("");
+
+    if id.is_empty() || !id.chars().all(|c| c.is_ascii_digit()) {
+        return Err("Webhook ID must be numeric".to_string());
+    }
+    if token.is_empty() || !token.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        return Err("Webhook token is missing or malformed".to_string());
+    }
+    if parts.next().is_some() {
+        return Err("Webhook URL has unexpected trailing path".to_string());
+    }
+
+    Ok(())
+}
+
+// [COMMAND] Validate and save the webhook URL - an empty URL reverts to the built-in one
+// With test set, a message is posted first so a dead webhook is caught at save time
+#[tauri::command]
+pub async fn set_webhook_config(url: String, test: Option<bool>) -> Result<WebhookResult, String> {
+    let url = url.trim().to_string();
+
+    let config = if url.is_empty() {
+        WebhookConfig { url: None }
+    } else {
+        validate_webhook_url(&url)?;
+
+        if test.unwrap_or(false) {
+            let response = reqwest::Client::new()
+                .post(&url)
+                .json(&serde_json::json!({ "content": "Wildflover webhook connected" }))
+                .send()
+                .await
+                .map_err(|e| format!("Network error: {}", e))?;
+
+            if !response.status().is_success() {
+                println!("[WEBHOOK] Test message rejected: {}", response.status());
+                return Err(format!("Webhook test failed: {}", response.status()));
+            }
+        }
+
+        WebhookConfig { url: Some(url) }
+    };
+
+    let file = get_webhook_config_file();
+    if let Some(parent) = file.parent() {
+        std::fs::create_dir_all(parent).ok();
+    }
+    let json = serde_json::to_string_pretty(&config)
+        .map_err(|e| format!("Failed to serialize webhook config: {}", e))?;
+    std::fs::write(&file, json).map_err(|e| format!("Failed to save webhook config: {}", e))?;
+
+    let message = if config.url.is_some() { "Webhook saved" } else { "Webhook reset to default" };
+    println!("[WEBHOOK] {}", message);
+    Ok(WebhookResult {
+        success: true,
+        message: message.to_string(),
+    })
+}
+
 // [STRUCT] Webhook embed field
 #[derive(Debug, Serialize)]
 struct EmbedField {
@@ -129,7 +231,7 @@ pub async fn send_login_webhook(user: UserInfo) -> WebhookResult {
     let client = reqwest::Client::new();
     
     match client
-        .post(LOGIN_WEBHOOK_URL)
+        .post(webhook_url())
         .json(&payload)
         .send()
         .await
@@ -204,7 +306,7 @@ pub async fn send_logout_webhook(user: UserInfo) -> WebhookResult {
     let client = reqwest::Client::new();
     
     match client
-        .post(LOGIN_WEBHOOK_URL)
+        .post(webhook_url())
         .json(&payload)
         .send()
         .await
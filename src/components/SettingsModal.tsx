@@ -175,12 +175,13 @@ const SettingsModal = ({ isOpen, onClose, onResetSelections }: SettingsModalProp
       
       if (result.success && result.path) {
         // Valid League of Legends.exe selected - save the path
-        const saved = await invoke<boolean>('set_game_path', { path: result.path });
+        // Backend returns the saved path - may be auto-corrected to the Game subfolder
+        const saved = await invoke<string>('set_game_path', { path: result.path });
         if (saved) {
-          setGamePath(result.path);
+          setGamePath(saved);
           setGamePathError(null);
           setGamePathSuccess(t('settings.gamePathSet'));
-          console.log('[SETTINGS-GAMEPATH] Set to:', result.path);
+          console.log('[SETTINGS-GAMEPATH] Set to:', saved);
           // Clear success message after 3 seconds
           setTimeout(() => setGamePathSuccess(null), 3000);
         } else {
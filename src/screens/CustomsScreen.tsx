@@ -22,6 +22,8 @@ interface FileSelectionResult {
     name: string;
     path: string;
     size: number;
+    valid: boolean;
+    reason?: string | null;
   }>;
 }
 
@@ -30,6 +32,10 @@ interface FileInfoResult {
   name: string;
   path: string;
   size: number;
+  valid: boolean;
+  reason?: string | null;
+  is_dir: boolean;
+  wad_count: number | null;
 }
 
 // [INTERFACE] Component props
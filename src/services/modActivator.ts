@@ -6,6 +6,7 @@
  */
 
 import { invoke } from '@tauri-apps/api/core';
+import { listen, type UnlistenFn } from '@tauri-apps/api/event';
 
 // [INTERFACE] Skin download request
 interface SkinDownloadRequest {
@@ -20,6 +21,14 @@ interface DownloadResult {
   success: boolean;
   path?: string;
   error?: string;
+  extract_stats?: ExtractStats | null;
+}
+
+// [INTERFACE] Files extracted vs skipped by the extraction filters
+interface ExtractStats {
+  extracted: number;
+  skipped: number;
+  skipped_names: string[];
 }
 
 // [INTERFACE] Mod item for activation
@@ -35,6 +44,31 @@ interface ActivationResult {
   message: string;
   error?: string;
   vanguard_blocked: boolean;
+  failed_mods?: FailedMod[];
+  timings?: ActivationTimings | null;
+  error_code?: string | null;
+}
+
+// [INTERFACE] Overlay state transition from overlay-status-changed event
+interface OverlayStatusChanged {
+  status: 'running' | 'stopped' | 'paused' | 'crashed';
+  pid: number | null;
+  mod_count: number;
+}
+
+// [INTERFACE] Activation phase durations in milliseconds
+interface ActivationTimings {
+  import_ms: number | null;
+  mkoverlay_ms: number | null;
+  overlay_start_ms: number | null;
+  total_ms: number;
+}
+
+// [INTERFACE] Mod skipped during activation
+interface FailedMod {
+  name: string;
+  path: string;
+  reason: string;
 }
 
 // [INTERFACE] DLL Fix status from backend
@@ -94,6 +128,7 @@ export interface ActivationProgress {
   vanguardBlocked?: boolean;
   currentItemIndex?: number;
   completedItems?: number[];
+  failedMods?: string[];
 }
 
 /**
@@ -128,12 +163,11 @@ class ModActivatorService {
    */
   async setManualGamePath(path: string): Promise<boolean> {
     try {
-      const result = await invoke<boolean>('set_game_path', { path });
-      if (result) {
-        this.gamePath = path;
-        console.log('[MOD-ACTIVATOR] Manual game path set:', path);
-      }
-      return result;
+      // Backend returns the saved path - may be auto-corrected to the Game subfolder
+      const savedPath = await invoke<string>('set_game_path', { path });
+      this.gamePath = savedPath;
+      console.log('[MOD-ACTIVATOR] Manual game path set:', savedPath);
+      return true;
     } catch (error) {
       console.error('[MOD-ACTIVATOR] Failed to set game path:', error);
       return false;
@@ -410,6 +444,12 @@ class ModActivatorService {
       // [COMPLETE] Mark all items as completed
       const allCompleted = Array.from({ length: totalItems }, (_, i) => i);
 
+      // [FAILED-MODS] Overlay runs with the rest - explain which mods are missing and why
+      const failedMods = (activationResult.failed_mods ?? []).map(
+        (failed) => `${failed.name} failed to import: ${failed.reason}`
+      );
+      failedMods.forEach((line) => console.warn('[MOD-ACTIVATOR]', line));
+
       if (activationResult.success) {
         this.overlayRunning = true;
         this.lastSelectionHash = currentHash;
@@ -419,7 +459,8 @@ class ModActivatorService {
           current: totalItems,
           total: totalItems,
           message: `${(elapsed / 1000).toFixed(1)}s`,
-          completedItems: allCompleted
+          completedItems: allCompleted,
+          failedMods: failedMods.length > 0 ? failedMods : undefined
         });
       } else {
         this.overlayRunning = false;
@@ -532,6 +573,17 @@ class ModActivatorService {
     }
   }
 
+  /**
+   * Subscribe to overlay state transitions pushed by the backend
+   */
+  async onOverlayStatusChanged(callback: (event: OverlayStatusChanged) => void): Promise<UnlistenFn> {
+    return listen<OverlayStatusChanged>('overlay-status-changed', (event) => {
+      this.overlayRunning = event.payload.status === 'running';
+      console.log('[MOD-ACTIVATOR] Overlay status changed:', event.payload.status);
+      callback(event.payload);
+    });
+  }
+
   /**
    * Get overlay running state (cached)
    */
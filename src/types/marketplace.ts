@@ -28,6 +28,7 @@ export interface MarketplaceMod {
   downloadUrl: string;
   previewUrl?: string;
   fileSize: number;
+  sha256?: string | null;
   downloadCount: number;
   likeCount: number;
   likedBy?: ModLiker[];
@@ -65,6 +66,7 @@ export interface MarketplaceUploadResult {
   modId?: string;
   commitUrl?: string;
   error?: string;
+  verified?: boolean;
 }
 
 // [INTERFACE] Filter state for marketplace